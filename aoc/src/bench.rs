@@ -0,0 +1,211 @@
+use common::mode::prepare;
+use common::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The separately timed stages of a solution.
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Timed runs of each stage, in the order of [`STAGES`].
+pub type Samples = [Vec<Duration>; 3];
+
+/// Times `S::parse`, `S::part1` and `S::part2` over `iterations` runs each.
+/// The input is prepared for the current parse mode once, outside the timings.
+pub fn sample<S: Solution>(input: &str, iterations: usize) -> Result<Samples, ParseError> {
+    let mut samples = Samples::default();
+
+    let input: &str = &prepare(input)?;
+    let parsed = S::parse(input)?;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(S::parse(black_box(input))?);
+        samples[0].push(start.elapsed());
+    }
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(S::part1(black_box(&parsed)));
+        samples[1].push(start.elapsed());
+    }
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(S::part2(black_box(&parsed)));
+        samples[2].push(start.elapsed());
+    }
+
+    Ok(samples)
+}
+
+/// Summary statistics for one stage of one day, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: String,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Timing {
+    pub fn from_samples(day: u8, stage: &str, samples: &[Duration]) -> Timing {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let n = nanos.len().max(1) as f64;
+        let mean = nanos.iter().sum::<u64>() as f64 / n;
+        let variance = nanos
+            .iter()
+            .map(|&x| (x as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Timing {
+            day,
+            stage: stage.to_string(),
+            median_ns: median(&nanos),
+            min_ns: nanos.first().copied().unwrap_or(0),
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+fn median(sorted: &[u64]) -> u64 {
+    match sorted.len() {
+        0 => 0,
+        n if n % 2 == 1 => sorted[n / 2],
+        n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02} {:<5}  median {:>10}  min {:>10}  stddev {:>10}",
+            self.day,
+            self.stage,
+            format_ns(self.median_ns),
+            format_ns(self.min_ns),
+            format_ns(self.stddev_ns)
+        )
+    }
+}
+
+pub fn format_ns(nanos: u64) -> String {
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// A saved set of benchmark results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub timings: Vec<Timing>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read benchmark from {}: {}", path.display(), e))?;
+        serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n")
+            .map_err(|e| format!("Failed to write benchmark to {}: {}", path.display(), e).into())
+    }
+
+    /// Timings whose median is more than `threshold` percent slower than the
+    /// same day and stage in `baseline`, paired with the baseline timing.
+    pub fn regressions<'a>(
+        &'a self,
+        baseline: &'a Report,
+        threshold: f64,
+    ) -> Vec<(&'a Timing, &'a Timing)> {
+        self.timings
+            .iter()
+            .filter_map(|timing| {
+                let previous = baseline
+                    .timings
+                    .iter()
+                    .find(|t| t.day == timing.day && t.stage == timing.stage)?;
+                let limit = previous.median_ns as f64 * (1.0 + threshold / 100.0);
+                (timing.median_ns as f64 > limit).then_some((timing, previous))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u8, stage: &str, median_ns: u64) -> Timing {
+        Timing {
+            day,
+            stage: stage.to_string(),
+            median_ns,
+            min_ns: median_ns,
+            stddev_ns: 0,
+        }
+    }
+
+    #[test]
+    fn test_statistics() {
+        let samples: Vec<Duration> = [4, 1, 3, 2]
+            .iter()
+            .map(|&n| Duration::from_nanos(n))
+            .collect();
+        let timing = Timing::from_samples(1, "parse", &samples);
+        assert_eq!(timing.median_ns, 2);
+        assert_eq!(timing.min_ns, 1);
+        // Population standard deviation of 1..=4 is ~1.118
+        assert_eq!(timing.stddev_ns, 1);
+    }
+
+    #[test]
+    fn test_regressions_beyond_threshold() {
+        let baseline = Report {
+            iterations: 5,
+            timings: vec![timing(6, "part1", 100), timing(6, "part2", 100)],
+        };
+        let current = Report {
+            iterations: 5,
+            timings: vec![
+                timing(6, "part1", 109),
+                timing(6, "part2", 111),
+                timing(7, "part1", 1000),
+            ],
+        };
+        let regressions = current.regressions(&baseline, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].0.stage, "part2");
+    }
+
+    #[test]
+    fn test_report_round_trips_through_json() {
+        let report = Report {
+            iterations: 3,
+            timings: vec![timing(1, "parse", 42)],
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+
+    #[test]
+    fn test_format_ns() {
+        assert_eq!(format_ns(950), "950ns");
+        assert_eq!(format_ns(12_345), "12.3µs");
+        assert_eq!(format_ns(45_600_000), "45.6ms");
+        assert_eq!(format_ns(9_044_816_808), "9.04s");
+    }
+}
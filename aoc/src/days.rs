@@ -0,0 +1,112 @@
+use crate::bench::{self, Samples};
+use common::{solve, ParseError, Solution, SolveError};
+use rand::rngs::StdRng;
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(u8, &str) -> Result<String, SolveError>,
+    pub bench: fn(&str, usize) -> Result<Samples, ParseError>,
+    /// A random valid input, its size being whatever suits the day.
+    pub generate: fn(&mut StdRng, usize) -> String,
+}
+
+const fn day<S: Solution>(generate: fn(&mut StdRng, usize) -> String) -> Day {
+    Day {
+        number: S::DAY,
+        solve: solve::<S>,
+        bench: bench::sample::<S>,
+        generate,
+    }
+}
+
+impl Day {
+    pub fn input_path(&self) -> String {
+        format!("day{:02}/input.txt", self.number)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day01::Day01>(day01::generate::input),
+    day::<day02::Day02>(day02::generate::input),
+    day::<day03::Day03>(day03::generate::input),
+    day::<day04::Day04>(day04::generate::input),
+    day::<day05::Day05>(day05::generate::input),
+    day::<day06::Day06>(day06::generate::input),
+    day::<day07::Day07>(day07::generate::input),
+    day::<day08::Day08>(day08::generate::input),
+    day::<day09::Day09>(day09::generate::input),
+    day::<day10::Day10>(day10::generate::input),
+    day::<day11::Day11>(day11::generate::input),
+    day::<day12::Day12>(day12::generate::input),
+    day::<day13::Day13>(day13::generate::input),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::mode::{with_parse_mode, ParseMode};
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_days_are_registered_in_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.number as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(6).map(|day| day.number), Some(6));
+        assert!(find(26).is_none());
+    }
+
+    #[test]
+    fn test_generated_inputs_are_valid_at_any_size() {
+        for day in DAYS {
+            for size in [0, 1, 2, 25] {
+                let input = (day.generate)(&mut StdRng::seed_from_u64(1), size);
+                for part in 1..=2 {
+                    let solved = with_parse_mode(ParseMode::Strict, || (day.solve)(part, &input));
+                    assert!(
+                        solved.is_ok(),
+                        "Day {} part {} of size {}: {}",
+                        day.number,
+                        part,
+                        size,
+                        solved.unwrap_err()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_answers_for_a_fixed_seed() {
+        // Pins each generator's output, which should only change on purpose
+        let expected = [
+            ("189873", "940905"),
+            ("9", "21"),
+            ("4941794", "1793938"),
+            ("11", "2"),
+            ("921", "343"),
+            ("25", "6"),
+            ("1360491", "19597096999542"),
+            ("20", "212"),
+            ("10741", "11298"),
+            ("18", "72"),
+            ("601393", "720552525396242"),
+            ("22510", "12754"),
+            // No prize is in reach once part 2 moves them
+            ("1802", "0"),
+        ];
+        for (day, (part1, part2)) in DAYS.iter().zip(expected) {
+            let input = (day.generate)(&mut StdRng::seed_from_u64(2024), 25);
+            assert_eq!((day.solve)(1, &input).unwrap(), part1, "Day {}", day.number);
+            assert_eq!((day.solve)(2, &input).unwrap(), part2, "Day {}", day.number);
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::days::Day;
+use crate::verify::{self, Check};
+use regex::Regex;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// The example input and stated answer of one part of a puzzle description.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// The examples of both parts of a saved puzzle description. Parts missing
+/// from the description, such as part 2 before part 1 is solved, are left out.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub parts: Vec<Example>,
+}
+
+impl Examples {
+    /// Reads a description saved from the puzzle page as HTML, or converted
+    /// to markdown as by `aoc-cli`.
+    pub fn load(path: &Path) -> Result<Examples, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read puzzle from {}: {}", path.display(), e))?;
+        let examples = Self::extract(&text);
+        if examples.parts.is_empty() {
+            return Err(
+                format!("{} does not look like a puzzle description", path.display()).into(),
+            );
+        }
+        Ok(examples)
+    }
+
+    /// Takes each part's example to be its first code block and its answer
+    /// to be the last emphasised code in its text, which is how the puzzles
+    /// are written. Part 2 reuses part 1's example when it has none of its own.
+    pub fn extract(text: &str) -> Examples {
+        let sections = if text.contains("<article") {
+            html_sections(text)
+        } else {
+            markdown_sections(text)
+        };
+
+        let mut parts: Vec<Example> = sections
+            .into_iter()
+            .take(2)
+            .map(|(blocks, answers)| Example {
+                input: blocks.into_iter().next(),
+                answer: answers.into_iter().last(),
+            })
+            .collect();
+        if let [first, second] = &mut parts[..] {
+            if second.input.is_none() {
+                second.input = first.input.clone();
+            }
+        }
+        Examples { parts }
+    }
+}
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static CODE_BLOCK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+static HTML_ANSWER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"<code><em>([^<]*)</em></code>|<em><code>([^<]*)</code></em>").unwrap()
+});
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+static MARKDOWN_ANSWER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\*{1,2}`([^`]+)`\*{1,2}|`\*{1,2}([^`*]+)\*{1,2}`").unwrap());
+
+/// The code blocks and emphasised code of each `<article>`.
+fn html_sections(text: &str) -> Vec<(Vec<String>, Vec<String>)> {
+    ARTICLE
+        .captures_iter(text)
+        .map(|article| {
+            let body = &article[1];
+            let blocks = CODE_BLOCK
+                .captures_iter(body)
+                .map(|c| html_text(&c[1]))
+                .collect();
+            let answers = HTML_ANSWER
+                .captures_iter(body)
+                .filter_map(|c| c.get(1).or(c.get(2)))
+                .map(|m| html_text(m.as_str()))
+                .collect();
+            (blocks, answers)
+        })
+        .collect()
+}
+
+/// `html` with tags such as highlighting `<em>`s removed and entities decoded.
+fn html_text(html: &str) -> String {
+    TAG.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The fenced or indented code blocks and emphasised code of the text before
+/// and after the "--- Part Two ---" heading.
+fn markdown_sections(text: &str) -> Vec<(Vec<String>, Vec<String>)> {
+    text.split("--- Part Two ---")
+        .filter(|section| !section.trim().is_empty())
+        .map(|section| {
+            let answers = MARKDOWN_ANSWER
+                .captures_iter(section)
+                .filter_map(|c| c.get(1).or(c.get(2)))
+                .map(|m| m.as_str().to_string())
+                .collect();
+            (markdown_blocks(section), answers)
+        })
+        .collect()
+}
+
+fn markdown_blocks(text: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<String> = None;
+    let mut fenced = false;
+    // Blank lines only belong to an indented block if it carries on after them
+    let mut blank_lines = 0;
+
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            blocks.extend(current.take());
+            if !fenced {
+                current = Some(String::new());
+            }
+            fenced = !fenced;
+        } else if fenced {
+            let block = current.get_or_insert_with(String::new);
+            block.push_str(line);
+            block.push('\n');
+        } else if let Some(code) = line.strip_prefix("    ") {
+            let block = current.get_or_insert_with(String::new);
+            block.push_str(&"\n".repeat(blank_lines));
+            block.push_str(code);
+            block.push('\n');
+            blank_lines = 0;
+        } else if line.trim().is_empty() && current.is_some() {
+            blank_lines += 1;
+        } else {
+            blocks.extend(current.take());
+            blank_lines = 0;
+        }
+    }
+    blocks.extend(current.filter(|_| !fenced));
+    blocks
+}
+
+/// Where `day`'s saved description is looked for when no path is given.
+pub fn default_puzzle_path(day: u8) -> PathBuf {
+    let dir = PathBuf::from(format!("day{:02}", day));
+    let html = dir.join("puzzle.html");
+    if html.is_file() {
+        html
+    } else {
+        dir.join("puzzle.md")
+    }
+}
+
+/// Solves each example and compares the result with its stated answer.
+pub fn check(day: &Day, examples: &Examples) -> Vec<Check> {
+    examples
+        .parts
+        .iter()
+        .zip(1..)
+        .map(|(example, part)| {
+            let outcome = match &example.input {
+                Some(input) => verify::compare((day.solve)(part, input), example.answer.as_deref()),
+                None => verify::Outcome::Error("no example found".to_string()),
+            };
+            Check {
+                day: day.number,
+                part,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+/// Where [`test_source`] is written for `day`.
+pub fn test_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{:02}", day))
+        .join("tests")
+        .join("examples.rs")
+}
+
+/// An integration test asserting each example's stated answer.
+pub fn test_source(day: u8, examples: &Examples) -> String {
+    let mut inputs: Vec<&str> = Vec::new();
+    for input in examples.parts.iter().filter_map(|e| e.input.as_deref()) {
+        if !inputs.contains(&input) {
+            inputs.push(input);
+        }
+    }
+    let name = |input: &str| match inputs.iter().position(|&i| i == input) {
+        Some(_) if inputs.len() == 1 => "EXAMPLE".to_string(),
+        Some(i) => format!("EXAMPLE_{}", i + 1),
+        None => unreachable!("every example input was collected"),
+    };
+
+    let mut source = format!(
+        "//! Generated by `aoc examples --day {0} --write` from the puzzle description.\n\
+         //! Regenerate it rather than editing by hand.\n\
+         \n\
+         use common::solve;\n\
+         use day{0:02}::Day{0:02};\n",
+        day
+    );
+    for input in &inputs {
+        source.push_str(&format!(
+            "\nconst {}: &str = {};\n",
+            name(input),
+            raw_string(input)
+        ));
+    }
+    for (example, part) in examples.parts.iter().zip(1..) {
+        match (&example.input, &example.answer) {
+            (Some(input), Some(answer)) => source.push_str(&format!(
+                "\n#[test]\nfn test_part{part}_example() {{\n    assert_eq!(solve::<Day{day:02}>({part}, {}).unwrap(), {:?});\n}}\n",
+                name(input),
+                answer,
+            )),
+            _ => source.push_str(&format!("\n// Part {} has no example with a stated answer\n", part)),
+        }
+    }
+    source
+}
+
+/// `text` as a raw string literal, with enough `#`s to hold any it contains.
+fn raw_string(text: &str) -> String {
+    let mut hashes = 0;
+    while text.contains(&format!("\"{}", "#".repeat(hashes))) {
+        hashes += 1;
+    }
+    let hashes = "#".repeat(hashes);
+    format!("r{0}\"{1}\"{0}", hashes, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::verify::Outcome;
+
+    const HTML: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+</code></pre>
+<p>Pair up the <em>smallest</em> numbers, giving <code>2</code>, then <code>1</code>.</p>
+<p>In the example above, this is <code>2 + 1 + 0 + 1 + 2 + 5</code>, a total distance of <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>2000468</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>So, for these example lists, the similarity score at the end of this process is <code><em>31</em></code> (<code>9 + 4 + 0 + 0 + 9 + 9</code>).</p>
+</article>
+</main>"#;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_extract_html() {
+        let examples = Examples::extract(HTML);
+        assert_eq!(
+            examples.parts,
+            vec![
+                Example {
+                    input: Some(EXAMPLE.to_string()),
+                    answer: Some("11".to_string()),
+                },
+                Example {
+                    input: Some(EXAMPLE.to_string()),
+                    answer: Some("31".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_html_highlighting_and_entities_are_removed() {
+        assert_eq!(
+            html_text("<em>X</em>MAS &lt;-&gt; &amp;&quot;"),
+            "XMAS <-> &\""
+        );
+    }
+
+    #[test]
+    fn test_extract_markdown() {
+        let markdown = "\\--- Day 1: Historian Hysteria ---\n\nFor example:\n\n    3   4\n\n    4   3\n\nA total distance of *`11`*!\n\n\\--- Part Two ---\n----------\n\n```\n1   1\n```\n\nThe score is `**31**`.\n";
+        let examples = Examples::extract(markdown);
+        assert_eq!(examples.parts.len(), 2);
+        assert_eq!(examples.parts[0].input.as_deref(), Some("3   4\n\n4   3\n"));
+        assert_eq!(examples.parts[0].answer.as_deref(), Some("11"));
+        assert_eq!(examples.parts[1].input.as_deref(), Some("1   1\n"));
+        assert_eq!(examples.parts[1].answer.as_deref(), Some("31"));
+    }
+
+    #[test]
+    fn test_check_against_solution() {
+        let mut examples = Examples::extract(HTML);
+        examples.parts[1].answer = Some("32".to_string());
+
+        let checks = check(days::find(1).unwrap(), &examples);
+        assert_eq!(checks[0].outcome, Outcome::Pass("11".to_string()));
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Fail {
+                expected: "32".to_string(),
+                actual: "31".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_generated_test_source() {
+        let source = test_source(1, &Examples::extract(HTML));
+        assert!(source.contains("const EXAMPLE: &str = r\"3   4\n"));
+        assert!(source.contains("assert_eq!(solve::<Day01>(2, EXAMPLE).unwrap(), \"31\");"));
+        assert_eq!(raw_string("say \"#hi\""), "r##\"say \"#hi\"\"##");
+    }
+}
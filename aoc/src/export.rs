@@ -0,0 +1,240 @@
+use common::render::{Colour, Frame};
+use common::Direction;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+/// Pixels along each side of a grid cell when none is given.
+pub const DEFAULT_SCALE: u32 = 8;
+
+const BACKGROUND: Colour = Colour::rgb(24, 24, 32);
+/// Tiles drawn with a character but no colour, such as walls.
+const TILE: Colour = Colour::rgb(90, 90, 100);
+
+/// A frame drawn as pixels: each cell is a square of its background colour
+/// with a smaller square in its foreground colour, if it has one.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<Colour>,
+}
+
+impl Image {
+    pub fn draw(frame: &Frame, scale: u32) -> Image {
+        let scale = scale.max(1);
+        let width = frame.cells.width() as u32 * scale;
+        let height = frame.cells.height() as u32 * scale;
+        let mut image = Image {
+            width,
+            height,
+            pixels: vec![BACKGROUND; (width * height) as usize],
+        };
+
+        let inset = scale / 4;
+        for (point, cell) in frame.cells.iter() {
+            let (x, y) = (point.col as u32 * scale, point.row as u32 * scale);
+            if let Some(bg) = cell.bg {
+                image.fill(x, y, scale, bg);
+            }
+            let fg = match cell.glyph {
+                '.' | ' ' => cell.fg,
+                _ => Some(cell.fg.unwrap_or(TILE)),
+            };
+            if let Some(fg) = fg {
+                image.fill(x + inset, y + inset, scale - 2 * inset, fg);
+            }
+        }
+
+        let mark = (scale / 4).max(1);
+        for &(point, corner, colour) in &frame.corners {
+            let (x, y) = (point.col as u32 * scale, point.row as u32 * scale);
+            let (dx, dy) = match corner {
+                Direction::UpLeft => (0, 0),
+                Direction::UpRight => (scale - mark, 0),
+                Direction::DownLeft => (0, scale - mark),
+                _ => (scale - mark, scale - mark),
+            };
+            image.fill(x + dx, y + dy, mark, colour);
+        }
+
+        image
+    }
+
+    fn fill(&mut self, x: u32, y: u32, size: u32, colour: Colour) {
+        for row in y..(y + size).min(self.height) {
+            for col in x..(x + size).min(self.width) {
+                self.pixels[(row * self.width + col) as usize] = colour;
+            }
+        }
+    }
+
+    fn rgb(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|c| [c.r, c.g, c.b]).collect()
+    }
+
+    pub fn write_png(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let file = File::create(path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&self.rgb())?;
+        Ok(())
+    }
+
+    /// The image as a GIF frame, with its own palette when it has few enough
+    /// colours, as drawings of puzzles do.
+    fn gif_frame(&self) -> gif::Frame<'static> {
+        let mut palette: HashMap<Colour, u8> = HashMap::new();
+        let mut indices = Vec::with_capacity(self.pixels.len());
+        for &colour in &self.pixels {
+            let next = palette.len();
+            if next > 255 && !palette.contains_key(&colour) {
+                return gif::Frame::from_rgb_speed(
+                    self.width as u16,
+                    self.height as u16,
+                    &self.rgb(),
+                    10,
+                );
+            }
+            indices.push(*palette.entry(colour).or_insert(next as u8));
+        }
+
+        let mut colours = vec![0; palette.len() * 3];
+        for (colour, index) in palette {
+            let i = index as usize * 3;
+            colours[i..i + 3].copy_from_slice(&[colour.r, colour.g, colour.b]);
+        }
+        gif::Frame::from_palette_pixels(
+            self.width as u16,
+            self.height as u16,
+            indices,
+            colours,
+            None,
+        )
+    }
+}
+
+/// Where to write the frames of a visualisation.
+pub struct Export {
+    /// Directory to write each frame to as `frame-NNNN.png`.
+    pub png_dir: Option<PathBuf>,
+    /// File to write all the frames to as an animated GIF.
+    pub gif: Option<PathBuf>,
+    pub scale: u32,
+    /// Frames a second of the GIF.
+    pub fps: f64,
+}
+
+impl Export {
+    /// Writes `frames` in every requested form, returning how many there were.
+    pub fn write(&self, frames: impl IntoIterator<Item = Frame>) -> Result<usize, Box<dyn Error>> {
+        if let Some(dir) = &self.png_dir {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+
+        let mut gif = None;
+        let mut count = 0;
+        for frame in frames {
+            let image = Image::draw(&frame, self.scale);
+            if image.width > u16::MAX as u32 || image.height > u16::MAX as u32 {
+                return Err("The map is too large for a GIF at this scale".into());
+            }
+
+            if let Some(dir) = &self.png_dir {
+                image.write_png(&dir.join(format!("frame-{:04}.png", count + 1)))?;
+            }
+            if let Some(path) = &self.gif {
+                if gif.is_none() {
+                    let file = File::create(path)
+                        .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+                    let mut encoder = gif::Encoder::new(
+                        BufWriter::new(file),
+                        image.width as u16,
+                        image.height as u16,
+                        &[],
+                    )?;
+                    encoder.set_repeat(gif::Repeat::Infinite)?;
+                    gif = Some(encoder);
+                }
+                let mut gif_frame = image.gif_frame();
+                // GIF delays are in hundredths of a second
+                gif_frame.delay = (100.0 / self.fps.max(0.01)).round().min(u16::MAX as f64) as u16;
+                gif.as_mut().unwrap().write_frame(&gif_frame)?;
+            }
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Grid, Point};
+    use std::env;
+    use std::process;
+
+    fn frame() -> Frame {
+        let map: Grid<char> = ".#\n..\n".parse().unwrap();
+        let mut frame = Frame::new(&map, |&c| c);
+        frame.highlight([Point::new(1, 0)], Colour::BLUE);
+        frame.overlay([Point::new(1, 1)], Some('@'), Colour::RED);
+        frame.mark_corner(Point::new(0, 0), Direction::DownRight, Colour::WHITE);
+        frame
+    }
+
+    #[test]
+    fn test_cells_are_drawn_as_squares() {
+        let image = Image::draw(&frame(), 4);
+        assert_eq!((image.width, image.height), (8, 8));
+        let at = |x: u32, y: u32| image.pixels[(y * image.width + x) as usize];
+        // Plain tile
+        assert_eq!(at(0, 0), BACKGROUND);
+        // Wall, inset from the cell's edge
+        assert_eq!(at(4, 0), BACKGROUND);
+        assert_eq!(at(5, 1), TILE);
+        // Highlighted cell and coloured glyph
+        assert_eq!(at(0, 4), Colour::BLUE);
+        assert_eq!(at(5, 5), Colour::RED);
+        // Corner mark
+        assert_eq!(at(3, 3), Colour::WHITE);
+        assert_eq!(at(2, 2), BACKGROUND);
+    }
+
+    #[test]
+    fn test_write_png_and_gif() {
+        let dir = env::temp_dir().join(format!("aoc-export-{}", process::id()));
+        let export = Export {
+            png_dir: Some(dir.join("frames")),
+            gif: Some(dir.join("frames.gif")),
+            scale: 2,
+            fps: 4.0,
+        };
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(export.write(vec![frame(), frame()]).unwrap(), 2);
+
+        let decoder = png::Decoder::new(File::open(dir.join("frames/frame-0002.png")).unwrap());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (4, 4));
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options
+            .read_info(File::open(dir.join("frames.gif")).unwrap())
+            .unwrap();
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 25);
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
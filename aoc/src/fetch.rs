@@ -0,0 +1,371 @@
+use common::input::InputSource;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The puzzle year these solutions are for.
+pub const YEAR: u16 = 2024;
+
+/// Environment variable holding the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding where downloaded inputs are cached.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Environment variable overriding the site inputs are downloaded from.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Why a puzzle input could not be fetched.
+#[derive(Debug)]
+pub enum FetchError {
+    /// There is no cached copy and no session token to download one with.
+    NoSession,
+    /// The server answered, but not with the input.
+    Status { url: String, code: u16 },
+    /// The server could not be reached and there is no cached copy.
+    Offline { url: String, reason: String },
+    /// The cache could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "No session token: set ${} or save it in {}",
+                SESSION_VAR,
+                config_dir().join("session").display()
+            ),
+            FetchError::Status { url, code } => write!(f, "{} answered {}", url, code),
+            // ureq's errors already name the URL
+            FetchError::Offline { reason, .. } => {
+                write!(f, "Nothing is cached and the download failed: {}", reason)
+            }
+            FetchError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Where a fetched input came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Downloaded,
+    Cache,
+    /// A refresh was asked for but the server could not be reached.
+    StaleCache,
+}
+
+#[derive(Debug)]
+pub struct Fetched {
+    pub input: String,
+    pub path: PathBuf,
+    pub origin: Origin,
+}
+
+/// Downloads puzzle inputs, keeping a copy of each under
+/// `<cache_dir>/<year>/dayNN.txt` so each is only downloaded once.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+}
+
+impl Fetcher {
+    /// A fetcher configured from the environment: the session comes from
+    /// `$AOC_SESSION` or `~/.config/aoc/session`, and inputs are cached in
+    /// `$AOC_CACHE_DIR` or `~/.cache/aoc`.
+    pub fn from_env() -> Fetcher {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| fs::read_to_string(config_dir().join("session")).ok())
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty());
+
+        Fetcher {
+            base_url: env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session,
+            cache_dir: env::var_os(CACHE_DIR_VAR)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| user_dir("XDG_CACHE_HOME", ".cache").join("aoc")),
+        }
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// The input for `day`, from the cache if it is there, otherwise
+    /// downloaded. With `refresh` it is downloaded again, falling back to the
+    /// cached copy if the server cannot be reached.
+    pub fn fetch(&self, year: u16, day: u8, refresh: bool) -> Result<Fetched, FetchError> {
+        let path = self.cache_path(year, day);
+        let cached = match fs::read_to_string(&path) {
+            Ok(input) => Some(input),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(source) => return Err(FetchError::Io { path, source }),
+        };
+
+        if let Some(input) = &cached {
+            if !refresh || self.session.is_none() {
+                return Ok(Fetched {
+                    input: input.clone(),
+                    path,
+                    origin: Origin::Cache,
+                });
+            }
+        }
+
+        let session = self.session.as_deref().ok_or(FetchError::NoSession)?;
+        match self.download(year, day, session) {
+            Ok(input) => {
+                write_atomically(&path, &input)?;
+                Ok(Fetched {
+                    input,
+                    path,
+                    origin: Origin::Downloaded,
+                })
+            }
+            Err(FetchError::Offline { url, reason }) => match cached {
+                Some(input) => {
+                    log::warn!("Using the cached input: {}", reason);
+                    Ok(Fetched {
+                        input,
+                        path,
+                        origin: Origin::StaleCache,
+                    })
+                }
+                None => Err(FetchError::Offline { url, reason }),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    fn download(&self, year: u16, day: u8, session: &str) -> Result<String, FetchError> {
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+        log::info!("Downloading {}", url);
+
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!(
+                "github.com/dglsparsons/aoc2024 aoc/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build();
+        let response = agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(code, _) => FetchError::Status {
+                    url: url.clone(),
+                    code,
+                },
+                ureq::Error::Transport(t) => FetchError::Offline {
+                    url: url.clone(),
+                    reason: t.to_string(),
+                },
+            })?;
+        response.into_string().map_err(|e| FetchError::Offline {
+            url: url.clone(),
+            reason: e.to_string(),
+        })
+    }
+}
+
+/// Reads `day`'s input as the runner commands do: an explicit `--input`, or
+/// the local file when it exists, otherwise the cached or downloaded copy.
+pub fn read_input(day: u8, arg: Option<&str>, default: &str) -> Result<String, Box<dyn Error>> {
+    let source = InputSource::resolve(arg, day, default);
+    match &source {
+        InputSource::Path(path) if arg.is_none() && !path.exists() => {
+            let fetched = Fetcher::from_env()
+                .fetch(YEAR, day, false)
+                .map_err(|e| format!("Day {:02}: {}", day, e))?;
+            Ok(fetched.input)
+        }
+        _ => Ok(source.read_to_string()?),
+    }
+}
+
+fn write_atomically(path: &Path, contents: &str) -> Result<(), FetchError> {
+    let io_error = |source| FetchError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    // A partly written input must never be mistaken for a cached one
+    let partial = path.with_extension("part");
+    fs::write(&partial, contents).map_err(io_error)?;
+    fs::rename(&partial, path).map_err(io_error)
+}
+
+fn config_dir() -> PathBuf {
+    user_dir("XDG_CONFIG_HOME", ".config").join("aoc")
+}
+
+/// `$var`, or `fallback` in the home directory.
+fn user_dir(var: &str, fallback: &str) -> PathBuf {
+    env::var_os(var)
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))
+        .unwrap_or_else(|| PathBuf::from(fallback))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::process;
+    use std::sync::mpsc;
+    use std::thread;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Serves `responses` in turn from a local port, sending back the request
+    /// line and cookie of each request it answers.
+    fn stand_in(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, requests) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (code, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_string();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if line.starts_with("GET") || line.to_lowercase().starts_with("cookie:") {
+                        request.push(line);
+                    }
+                }
+                sender.send(request.join("\n")).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    code,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    /// A URL nothing can listen on, as port 0 can't be connected to.
+    fn unreachable() -> String {
+        "http://127.0.0.1:0".to_string()
+    }
+
+    fn fetcher(base_url: String, cache_dir: PathBuf) -> Fetcher {
+        Fetcher {
+            base_url,
+            session: Some("abc123".to_string()),
+            cache_dir,
+        }
+    }
+
+    #[test]
+    fn test_downloads_once_then_uses_cache() {
+        let (url, requests) = stand_in(vec![(200, "3   4\n4   3\n")]);
+        let cache = temp_dir("download");
+        let fetcher = fetcher(url, cache.clone());
+
+        let fetched = fetcher.fetch(2024, 1, false).unwrap();
+        assert_eq!(fetched.origin, Origin::Downloaded);
+        assert_eq!(fetched.input, "3   4\n4   3\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            "GET /2024/day/1/input HTTP/1.1\nCookie: session=abc123"
+        );
+        assert_eq!(fetched.path, cache.join("2024").join("day01.txt"));
+
+        // The stand-in has no responses left, so this must come from the cache
+        let cached = fetcher.fetch(2024, 1, false).unwrap();
+        assert_eq!(cached.origin, Origin::Cache);
+        assert_eq!(cached.input, fetched.input);
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_refresh_falls_back_to_cache_when_offline() {
+        let cache = temp_dir("offline");
+        let fetcher = fetcher(unreachable(), cache.clone());
+        write_atomically(&fetcher.cache_path(2024, 6), "#^\n").unwrap();
+
+        let fetched = fetcher.fetch(2024, 6, true).unwrap();
+        assert_eq!(fetched.origin, Origin::StaleCache);
+        assert_eq!(fetched.input, "#^\n");
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_offline_without_cache_fails() {
+        let fetcher = fetcher(unreachable(), temp_dir("empty"));
+        let error = fetcher.fetch(2024, 6, false).unwrap_err();
+        assert!(matches!(error, FetchError::Offline { .. }), "{}", error);
+    }
+
+    #[test]
+    fn test_error_status_is_not_cached() {
+        let (url, _requests) = stand_in(vec![(404, "Please don't repeatedly request this")]);
+        let cache = temp_dir("status");
+        let fetcher = fetcher(url, cache.clone());
+
+        let error = fetcher.fetch(2024, 25, false).unwrap_err();
+        assert!(matches!(error, FetchError::Status { code: 404, .. }));
+        assert!(!fetcher.cache_path(2024, 25).exists());
+    }
+
+    #[test]
+    fn test_download_needs_a_session() {
+        let fetcher = Fetcher {
+            session: None,
+            ..fetcher(unreachable(), temp_dir("no-session"))
+        };
+        assert!(matches!(
+            fetcher.fetch(2024, 1, false),
+            Err(FetchError::NoSession)
+        ));
+    }
+}
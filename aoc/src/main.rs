@@ -0,0 +1,625 @@
+use clap::{Parser, Subcommand};
+use common::batch::{self, Table};
+use common::input::InputSource;
+use common::logging::{self, Level, LOG_LEVEL_VAR};
+use common::mode::{self, ParseMode, PARSE_MODE_VAR};
+use common::output::{self, Format, Record};
+use common::render;
+use common::Solution;
+use day01::lists::{self, Columns, IdKind, Metric};
+use day01::report::{self, ReportFormat};
+use day02::part2::{verdicts, Reason, Verdict};
+use day02::rules::Rules;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Instant;
+
+mod bench;
+mod days;
+mod examples;
+mod export;
+mod fetch;
+mod scaffold;
+mod verify;
+mod visualise;
+mod watch;
+
+use bench::{Report, Timing};
+use days::Day;
+use examples::Examples;
+use export::Export;
+use fetch::{Fetcher, Origin};
+use verify::{Answers, Summary};
+use watch::Watch;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    /// How to treat untidy input: `lenient` normalises it, `strict` rejects it
+    #[arg(long, global = true, default_value_t = ParseMode::Lenient, env = PARSE_MODE_VAR)]
+    parse_mode: ParseMode,
+
+    /// How much the solutions log to stderr: quiet, info, debug or trace
+    #[arg(long, global = true, default_value_t = Level::Quiet, env = LOG_LEVEL_VAR)]
+    log_level: Level,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run {
+        /// Day to solve
+        #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+
+        /// Part to solve; both parts are solved when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Solve every registered day
+        #[arg(long)]
+        all: bool,
+
+        /// Input file, `-` for stdin, or a directory or glob pattern of inputs to solve in
+        /// parallel; defaults to $AOC_INPUT_DIR or dayNN/input.txt, then the cached or
+        /// downloaded input
+        #[arg(long, conflicts_with = "all")]
+        input: Option<String>,
+
+        /// Print answers as text, or as one JSON object per part
+        #[arg(long, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check answers against those recorded in an answers file
+    Verify {
+        /// Day to check; every registered day is checked when omitted
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// TOML file of expected answers, with a [dayNN] table per day
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+
+        /// Input file, or `-` for stdin; defaults to $AOC_INPUT_DIR or dayNN/input.txt,
+        /// then the cached or downloaded input
+        #[arg(long, requires = "day")]
+        input: Option<String>,
+    },
+    /// Time parsing and each part separately over several iterations
+    Bench {
+        /// Day to benchmark; every registered day is benchmarked when omitted
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Number of timed runs of each stage
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+
+        /// Write the results as JSON to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare against results previously written with --save
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Percentage a median may slow down by before it counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download a day's input using the session in $AOC_SESSION, caching it
+    Fetch {
+        /// Day to fetch
+        #[arg(long)]
+        day: u8,
+
+        /// Puzzle year
+        #[arg(long, default_value_t = fetch::YEAR)]
+        year: u16,
+
+        /// Download again even if the input is cached
+        #[arg(long)]
+        refresh: bool,
+
+        /// Also copy the input to this file, e.g. day06/input.txt
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Check a day against the examples in its saved puzzle description
+    Examples {
+        /// Day to check
+        #[arg(long)]
+        day: u8,
+
+        /// Puzzle description saved as HTML or markdown; defaults to
+        /// dayNN/puzzle.html, then dayNN/puzzle.md
+        #[arg(long)]
+        puzzle: Option<PathBuf>,
+
+        /// Also write the examples as tests to dayNN/tests/examples.rs
+        #[arg(long)]
+        write: bool,
+    },
+    /// Print a random valid input for a day, e.g. to pipe into `run --input -`
+    Generate {
+        /// Day to generate an input for
+        #[arg(long)]
+        day: u8,
+
+        /// Size of the input: lines, machines or grid side, depending on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Seed for a reproducible input; random when omitted
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Draw a grid day being solved in the terminal, step by step
+    Visualise {
+        /// Day to draw: 6, 8, 10 or 12
+        #[arg(long)]
+        day: u8,
+
+        /// Input file, or `-` for stdin; defaults to $AOC_INPUT_DIR or dayNN/input.txt,
+        /// then the cached or downloaded input
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Frames a second to animate at; only the finished picture is drawn when omitted.
+        /// GIFs default to 10
+        #[arg(long)]
+        fps: Option<f64>,
+
+        /// Only draw every Nth step; the finished picture is always drawn
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+
+        /// Write each frame as a PNG to this directory instead of the terminal
+        #[arg(long)]
+        png: Option<PathBuf>,
+
+        /// Write the frames as an animated GIF to this file instead of the terminal
+        #[arg(long)]
+        gif: Option<PathBuf>,
+
+        /// Pixels along each side of a grid cell in PNGs and GIFs
+        #[arg(long, default_value_t = export::DEFAULT_SCALE)]
+        scale: u32,
+    },
+    /// Rerun a day's tests, then its solution, whenever its sources or input change
+    Watch {
+        /// Day to watch
+        #[arg(long)]
+        day: u8,
+
+        /// Input file; defaults to $AOC_INPUT_DIR or dayNN/input.txt
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Compare day 1's two lists, read from any pair of columns, by another metric
+    Lists {
+        /// Input file, or `-` for stdin; defaults to $AOC_INPUT_DIR or day01/input.txt,
+        /// then the cached or downloaded input
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Columns holding the left and right lists, counting from 1
+        #[arg(long, default_value_t = Columns::default())]
+        columns: Columns,
+
+        /// Type to read the IDs as: i32, i64 or big for arbitrary precision
+        #[arg(long, default_value_t = IdKind::I32)]
+        ids: IdKind,
+
+        /// distance, squared, similarity, jaccard, or diff to list the IDs
+        /// whose counts differ
+        #[arg(long, default_value_t = Metric::Distance)]
+        metric: Metric,
+    },
+    /// Show where day 1's lists disagree: each sorted pair's distance, the largest
+    /// contributors and the IDs found in only one list
+    Reconcile {
+        /// Input file, or `-` for stdin; defaults to $AOC_INPUT_DIR or day01/input.txt,
+        /// then the cached or downloaded input
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Columns holding the left and right lists, counting from 1
+        #[arg(long, default_value_t = Columns::default())]
+        columns: Columns,
+
+        /// Type to read the IDs as: i32, i64 or big for arbitrary precision
+        #[arg(long, default_value_t = IdKind::I32)]
+        ids: IdKind,
+
+        /// Number of pairs with the largest distances to list
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+
+        /// Write the report as aligned text or as CSV
+        #[arg(long, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+    },
+    /// Check day 2's reports under other rules, with the fewest removals each needs
+    Reports {
+        /// Input file, or `-` for stdin; defaults to $AOC_INPUT_DIR or day02/input.txt,
+        /// then the cached or downloaded input
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Smallest change allowed between adjacent levels; 0 allows equal levels
+        #[arg(long, default_value_t = Rules::default().min_step)]
+        min_step: u32,
+
+        /// Largest change allowed between adjacent levels
+        #[arg(long, default_value_t = Rules::default().max_step)]
+        max_step: u32,
+
+        /// Levels that may be removed from a report to make it safe
+        #[arg(long, default_value_t = Rules::default().max_removals)]
+        removals: usize,
+    },
+    /// Generate the package for a new day and register it with the runner
+    New {
+        /// Day to create
+        #[arg(long)]
+        day: u8,
+
+        /// Root of the workspace to add the day to
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+    mode::set_parse_mode(cli.parse_mode);
+    logging::init(cli.log_level);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all,
+            input,
+            format,
+        } => {
+            let number = if all {
+                None
+            } else {
+                Some(day.expect("clap requires --day without --all"))
+            };
+
+            for day in select_days(number)? {
+                match input.as_deref().filter(|arg| batch::is_batch(arg)) {
+                    Some(arg) => run_batch(day, part, arg, format)?,
+                    None => run_day(day, part, input.as_deref(), format)?,
+                }
+            }
+        }
+        Command::Verify {
+            day,
+            answers,
+            input,
+        } => {
+            let answers = Answers::load(&answers)?;
+            let mut checks = Vec::new();
+
+            for day in select_days(day)? {
+                for check in verify::check_day(day, input.as_deref(), &answers) {
+                    println!("{}", check);
+                    checks.push(check);
+                }
+            }
+
+            let summary = Summary::of(&checks);
+            println!("{}", summary);
+            if summary.failed > 0 {
+                return Err(format!("{} answer(s) did not verify", summary.failed).into());
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let mut timings = Vec::new();
+            for day in select_days(day)? {
+                timings.extend(bench_day(day, iterations as usize)?);
+            }
+            let report = Report {
+                iterations: iterations as usize,
+                timings,
+            };
+
+            if let Some(path) = save {
+                report.save(&path)?;
+            }
+
+            if let Some(path) = baseline {
+                let baseline = Report::load(&path)?;
+                let regressions = report.regressions(&baseline, threshold);
+                for (timing, previous) in &regressions {
+                    println!(
+                        "Regression: day {:02} {} median {}, was {}",
+                        timing.day,
+                        timing.stage,
+                        bench::format_ns(timing.median_ns),
+                        bench::format_ns(previous.median_ns)
+                    );
+                }
+                if !regressions.is_empty() {
+                    return Err(format!(
+                        "{} timing(s) regressed by more than {}%",
+                        regressions.len(),
+                        threshold
+                    )
+                    .into());
+                }
+            }
+        }
+        Command::Fetch {
+            day,
+            year,
+            refresh,
+            output,
+        } => {
+            let fetched = Fetcher::from_env()
+                .fetch(year, day, refresh)
+                .map_err(|e| e.to_string())?;
+            let how = match fetched.origin {
+                Origin::Downloaded => "downloaded to",
+                Origin::Cache => "cached at",
+                Origin::StaleCache => "offline, using",
+            };
+            println!("Day {:02}: {} {}", day, how, fetched.path.display());
+
+            if let Some(path) = output {
+                fs::write(&path, &fetched.input)
+                    .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+            }
+        }
+        Command::Examples { day, puzzle, write } => {
+            let number = day;
+            let day = days::find(number).ok_or(format!("Day {} is not implemented", number))?;
+            let puzzle = puzzle.unwrap_or_else(|| examples::default_puzzle_path(number));
+            let examples = Examples::load(&puzzle)?;
+
+            let checks = examples::check(day, &examples);
+            for check in &checks {
+                println!("{}", check);
+            }
+
+            if write {
+                let path = examples::test_path(number);
+                fs::create_dir_all(path.parent().unwrap())?;
+                fs::write(&path, examples::test_source(number, &examples))?;
+                println!("Wrote {}", path.display());
+            }
+
+            let summary = Summary::of(&checks);
+            if summary.failed > 0 {
+                return Err(format!("{} example(s) did not match", summary.failed).into());
+            }
+        }
+        Command::Generate { day, size, seed } => {
+            let day = days::find(day).ok_or(format!("Day {} is not implemented", day))?;
+            let mut rng = match seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            };
+            print!("{}", (day.generate)(&mut rng, size));
+        }
+        Command::Visualise {
+            day,
+            input,
+            fps,
+            every,
+            png,
+            gif,
+            scale,
+        } => {
+            let number = day;
+            let day = days::find(number).ok_or(format!("Day {} is not implemented", number))?;
+            let frames =
+                visualise::find(number).ok_or(format!("Day {} has no visualisation", number))?;
+            let input = fetch::read_input(number, input.as_deref(), &day.input_path())?;
+            let frames = frames(&input).map_err(|e| format!("Day {:02}: {}", number, e))?;
+            let frames = render::every(frames, every);
+
+            if png.is_some() || gif.is_some() {
+                let export = Export {
+                    png_dir: png,
+                    gif,
+                    scale,
+                    fps: fps.unwrap_or(10.0),
+                };
+                let count = export.write(frames)?;
+                for path in export.png_dir.iter().chain(&export.gif) {
+                    println!("Wrote {} frames to {}", count, path.display());
+                }
+            } else {
+                render::play(frames, fps, &mut io::stdout().lock())?;
+            }
+        }
+        Command::Watch { day, input } => {
+            let number = day;
+            let day = days::find(number).ok_or(format!("Day {} is not implemented", number))?;
+            let InputSource::Path(input) =
+                InputSource::resolve(input.as_deref(), number, day.input_path())
+            else {
+                return Err("Only an input file can be watched, not stdin".into());
+            };
+            let input = fs::canonicalize(&input).map_err(|_| {
+                format!(
+                    "No input at {}, fetch it with `aoc fetch --day {} --output {0}`",
+                    input.display(),
+                    number
+                )
+            })?;
+
+            Watch {
+                day: number,
+                root: fs::canonicalize(".")?,
+                input,
+            }
+            .run()?;
+        }
+        Command::Lists {
+            input,
+            columns,
+            ids,
+            metric,
+        } => {
+            let input = fetch::read_input(1, input.as_deref(), "day01/input.txt")?;
+            let measured = lists::measure(&input, columns, ids, metric)
+                .map_err(|e| format!("Day 01: {}", e))?;
+            println!("{}", measured);
+        }
+        Command::Reconcile {
+            input,
+            columns,
+            ids,
+            top,
+            format,
+        } => {
+            let input = fetch::read_input(1, input.as_deref(), "day01/input.txt")?;
+            let report = report::report(&input, columns, ids, top, format)
+                .map_err(|e| format!("Day 01: {}", e))?;
+            print!("{}", report);
+        }
+        Command::Reports {
+            input,
+            min_step,
+            max_step,
+            removals,
+        } => {
+            if min_step > max_step {
+                return Err("--min-step must not be larger than --max-step".into());
+            }
+            let rules = Rules {
+                min_step,
+                max_step,
+                max_removals: removals,
+            };
+            let input = fetch::read_input(2, input.as_deref(), "day02/input.txt")?;
+            let reports = day02::Day02::parse(&input).map_err(|e| format!("Day 02: {}", e))?;
+
+            let mut table = Table::new(&["Report", "Removals needed", "Safe", "Verdict"]);
+            let mut safe = 0;
+            // Linear for at most one removal, while the fewest removals
+            // beyond that take quadratic time to find
+            let verdicts = verdicts(&reports, &rules);
+            for (i, (levels, verdict)) in reports.iter().zip(verdicts).enumerate() {
+                let removals = match &verdict {
+                    Verdict::Safe => Some(0),
+                    Verdict::SafeWithout(removed) => Some(removed.len()),
+                    Verdict::Unsafe(_) if rules.max_removals >= 2 => rules.min_removals(levels),
+                    Verdict::Unsafe(_) => None,
+                };
+                safe += usize::from(verdict.is_safe());
+                table.push(vec![
+                    (i + 1).to_string(),
+                    match (removals, &verdict) {
+                        (Some(removals), _) => removals.to_string(),
+                        (None, Verdict::Unsafe(Reason::NoLevels)) => "-".to_string(),
+                        (None, _) => format!("more than {}", rules.max_removals),
+                    },
+                    if verdict.is_safe() { "yes" } else { "no" }.to_string(),
+                    verdict.to_string(),
+                ]);
+            }
+            print!("{}", table);
+            println!("Safe reports: {} of {}", safe, reports.len());
+        }
+        Command::New { day, root } => {
+            for path in scaffold::new_day(&root, day)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn bench_day(day: &Day, iterations: usize) -> Result<Vec<Timing>, Box<dyn Error>> {
+    let input = fetch::read_input(day.number, None, &day.input_path())?;
+    let samples =
+        (day.bench)(&input, iterations).map_err(|e| format!("Day {:02}: {}", day.number, e))?;
+
+    let timings: Vec<Timing> = bench::STAGES
+        .iter()
+        .zip(&samples)
+        .map(|(stage, samples)| Timing::from_samples(day.number, stage, samples))
+        .collect();
+    for timing in &timings {
+        println!("{}", timing);
+    }
+    Ok(timings)
+}
+
+/// The requested day, or every registered day when none is given.
+fn select_days(number: Option<u8>) -> Result<Vec<&'static Day>, Box<dyn Error>> {
+    match number {
+        Some(number) => Ok(vec![
+            days::find(number).ok_or(format!("Day {} is not implemented", number))?
+        ]),
+        None => Ok(days::DAYS.iter().collect()),
+    }
+}
+
+fn run_day(
+    day: &Day,
+    part: Option<u8>,
+    input: Option<&str>,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let input = fetch::read_input(day.number, input, &day.input_path())?;
+    let input_hash = output::input_hash(&input);
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = (day.solve)(part, &input)
+            .map_err(|e| format!("Day {:02} part {}: {}", day.number, part, e))?;
+        let record = Record {
+            day: day.number,
+            part,
+            answer,
+            elapsed: start.elapsed(),
+            input_hash: input_hash.clone(),
+            exact: true,
+        };
+        output::emit(format, &record, |answer| {
+            format!("Day {:02} part {}: {}", day.number, part, answer)
+        });
+    }
+
+    Ok(())
+}
+
+/// Solves `day` for each input named by the batch argument `arg` in parallel,
+/// printing a table of answers per input.
+fn run_batch(day: &Day, part: Option<u8>, arg: &str, format: Format) -> Result<(), Box<dyn Error>> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    output::solve_batch(day.number, &parts, arg, format, |part, input| {
+        (day.solve)(part, input).map_err(|e| e.to_string())
+    })
+    .map_err(|e| format!("Day {:02}: {}", day.number, e).into())
+}
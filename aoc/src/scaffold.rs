@@ -0,0 +1,300 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Creates the `dayNN` package under the workspace at `root` and registers it
+/// with the workspace and the runner, returning the files written. Nothing is
+/// written if the day already exists or cannot be registered.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not an Advent of Code day", day).into());
+    }
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists, not overwriting it", dir.display()).into());
+    }
+
+    // Work out every edit before touching the disk
+    let registrations = [
+        (
+            root.join("Cargo.toml"),
+            register_in(&root.join("Cargo.toml"), day, |d| {
+                format!("\"day{:02}\",", d)
+            })?,
+        ),
+        (
+            root.join("aoc").join("Cargo.toml"),
+            register_in(&root.join("aoc").join("Cargo.toml"), day, |d| {
+                format!("day{0:02} = {{ path = \"../day{0:02}\" }}", d)
+            })?,
+        ),
+        (
+            root.join("aoc").join("src").join("days.rs"),
+            register_in(&root.join("aoc").join("src").join("days.rs"), day, |d| {
+                format!(
+                    "day::<day{0:02}::Day{0:02}>(day{0:02}::generate::input),",
+                    d
+                )
+            })?,
+        ),
+    ];
+
+    let mut written = Vec::new();
+    for (path, contents) in templates(day) {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+    for (path, registered) in registrations {
+        fs::write(&path, registered)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// The contents of `path` with `day` registered in it, see [`register`].
+fn register_in(
+    path: &Path,
+    day: u8,
+    line: impl Fn(u8) -> String,
+) -> Result<String, Box<dyn Error>> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    register(&text, day, line).ok_or_else(|| {
+        format!(
+            "Could not find where to register day{:02} in {}",
+            day,
+            path.display()
+        )
+        .into()
+    })
+}
+
+/// Inserts `line(day)` after the line for the latest earlier day, matching its
+/// indentation, or returns `None` if there is no earlier day to follow.
+fn register(text: &str, day: u8, line: impl Fn(u8) -> String) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let (index, indent) = (1..day).rev().find_map(|previous| {
+        let entry = line(previous);
+        lines.iter().position(|l| l.trim() == entry).map(|index| {
+            let indent = &lines[index][..lines[index].len() - lines[index].trim_start().len()];
+            (index, indent)
+        })
+    })?;
+
+    let mut registered: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    registered.insert(index + 1, format!("{}{}", indent, line(day)));
+    Some(registered.join("\n") + "\n")
+}
+
+/// The files of a new day's package, relative to its directory.
+fn templates(day: u8) -> Vec<(&'static str, String)> {
+    let name = format!("day{:02}", day);
+    let title = format!("Day{:02}", day);
+
+    let cargo_toml = format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+common = {{ path = "../common" }}
+rand = "0.8"
+
+[[bin]]
+name = "{name}-part1"
+path = "src/bin/part1.rs"
+
+[[bin]]
+name = "{name}-part2"
+path = "src/bin/part2.rs"
+"#
+    );
+
+    let lib = format!(
+        r#"use common::mode::content_lines;
+use common::{{ParseError, Solution}};
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {{
+    content_lines(input)
+        .map(|line| line.map(|(_, line)| line.to_string()))
+        .collect()
+}}
+
+pub struct {title};
+
+impl Solution for {title} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        parse_input(input)
+    }}
+
+    fn part1(lines: &Self::Input) -> usize {{
+        part1::answer(lines)
+    }}
+
+    fn part2(lines: &Self::Input) -> usize {{
+        part2::answer(lines)
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "needs the puzzle's example and answers"]
+    fn test_example() {{
+        let lines = {title}::parse(EXAMPLE).unwrap();
+        assert_eq!({title}::part1(&lines), 0);
+        assert_eq!({title}::part2(&lines), 0);
+    }}
+}}
+"#
+    );
+
+    let part = |part: u8| {
+        format!(
+            r#"use crate::parse_input;
+
+pub fn answer(_lines: &[String]) -> usize {{
+    // TODO: solve part {part}
+    0
+}}
+
+pub fn solve(input: &str) -> usize {{
+    let lines = parse_input(input).expect("Failed to parse input");
+    answer(&lines)
+}}
+"#
+        )
+    };
+
+    let bin = |part: u8| {
+        format!(
+            r#"use common::output::run_part;
+
+fn main() {{
+    run_part({day}, {part}, {name}::part{part}::solve, |answer| {{
+        format!("Part {part}: {{}}", answer)
+    }});
+}}
+"#
+        )
+    };
+
+    let generate = r#"use rand::Rng;
+
+/// `size` lines of a random input.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    // TODO: generate the puzzle's input format
+    (0..size)
+        .map(|_| format!("{}\n", rng.gen_range(0..100)))
+        .collect()
+}
+"#
+    .to_string();
+
+    vec![
+        ("Cargo.toml", cargo_toml),
+        ("src/lib.rs", lib),
+        ("src/generate.rs", generate),
+        ("src/part1.rs", part(1)),
+        ("src/part2.rs", part(2)),
+        ("src/bin/part1.rs", bin(1)),
+        ("src/bin/part2.rs", bin(2)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    /// A workspace laid out like this one, with days 1 and 2.
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::create_dir_all(root.join("day02")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\nserde = \"1\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "pub const DAYS: &[Day] = &[\n    day::<day01::Day01>(day01::generate::input),\n    day::<day02::Day02>(day02::generate::input),\n];\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn test_new_day_is_generated_and_registered() {
+        let root = workspace("new");
+        let written = new_day(&root, 3).unwrap();
+        assert_eq!(written.len(), 10);
+
+        let bin = fs::read_to_string(root.join("day03/src/bin/part2.rs")).unwrap();
+        assert!(bin.contains("run_part(3, 2, day03::part2::solve"));
+        assert!(fs::read_to_string(root.join("day03/src/lib.rs"))
+            .unwrap()
+            .contains("impl Solution for Day03"));
+
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n    \"day03\",\n]\n"
+        );
+        assert!(fs::read_to_string(root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .contains("day02 = { path = \"../day02\" }\nday03 = { path = \"../day03\" }\nserde"));
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("    day::<day02::Day02>(day02::generate::input),\n    day::<day03::Day03>(day03::generate::input),\n];"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_existing_day_is_not_overwritten() {
+        let root = workspace("existing");
+        let error = new_day(&root, 2).unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("already exists, not overwriting it"));
+        assert_eq!(fs::read_dir(root.join("day02")).unwrap().count(), 0);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_register_needs_an_earlier_day() {
+        assert_eq!(register("[dependencies]\n", 3, |d| d.to_string()), None);
+        assert_eq!(
+            register("a\n  1\n  4\nb\n", 3, |d| d.to_string()).as_deref(),
+            Some("a\n  1\n  3\n  4\nb\n")
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use crate::days::Day;
+use crate::fetch;
+use common::SolveError;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Expected answers keyed by day and part, as recorded in `answers.toml`:
+///
+/// ```toml
+/// [day06]
+/// part1 = 4665
+/// part2 = 1688
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    expected: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read answers from {}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, Box<dyn Error>> {
+        let table: toml::Table = text.parse()?;
+        let mut expected = BTreeMap::new();
+
+        for (day_key, parts) in &table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u8>().ok())
+                .ok_or(format!(
+                    "Expected a table named like [day06], found [{}]",
+                    day_key
+                ))?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("[{}] should be a table of part answers", day_key))?;
+
+            for (part_key, answer) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => return Err(format!("Unknown part {:?} in [{}]", part_key, day_key).into()),
+                };
+                // Answers may be written as integers or as strings
+                let answer = match answer {
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::String(s) => s.clone(),
+                    _ => {
+                        return Err(format!(
+                            "{}.{} should be an integer or string",
+                            day_key, part_key
+                        )
+                        .into())
+                    }
+                };
+                expected.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass(String),
+    Fail { expected: String, actual: String },
+    Missing(String),
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {:02} part {}: ", self.day, self.part)?;
+        match &self.outcome {
+            Outcome::Pass(answer) => write!(f, "pass ({})", answer),
+            Outcome::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, got {})", expected, actual)
+            }
+            Outcome::Missing(answer) => write!(f, "missing (got {})", answer),
+            Outcome::Error(e) => write!(f, "FAIL ({})", e),
+        }
+    }
+}
+
+/// Solves both parts of `day` and compares them with the recorded answers.
+pub fn check_day(day: &Day, input: Option<&str>, answers: &Answers) -> Vec<Check> {
+    let input = fetch::read_input(day.number, input, &day.input_path());
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let outcome = match &input {
+                Ok(input) => compare((day.solve)(part, input), answers.get(day.number, part)),
+                Err(e) => Outcome::Error(e.to_string()),
+            };
+            Check {
+                day: day.number,
+                part,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+pub fn compare(actual: Result<String, SolveError>, expected: Option<&str>) -> Outcome {
+    match (actual, expected) {
+        (Err(e), _) => Outcome::Error(e.to_string()),
+        (Ok(actual), None) => Outcome::Missing(actual),
+        (Ok(actual), Some(expected)) if actual == expected => Outcome::Pass(actual),
+        (Ok(actual), Some(expected)) => Outcome::Fail {
+            expected: expected.to_string(),
+            actual,
+        },
+    }
+}
+
+/// Counts of passing, failing and missing checks.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+}
+
+impl Summary {
+    pub fn of(checks: &[Check]) -> Summary {
+        let mut summary = Summary::default();
+        for check in checks {
+            match check.outcome {
+                Outcome::Pass(_) => summary.passed += 1,
+                Outcome::Fail { .. } | Outcome::Error(_) => summary.failed += 1,
+                Outcome::Missing(_) => summary.missing += 1,
+            }
+        }
+        summary
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} missing",
+            self.passed, self.failed, self.missing
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("[day06]\npart1 = 4665\npart2 = \"1688\"\n").unwrap();
+        assert_eq!(answers.get(6, 1), Some("4665"));
+        assert_eq!(answers.get(6, 2), Some("1688"));
+        assert_eq!(answers.get(7, 1), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_keys() {
+        assert!(Answers::parse("[six]\npart1 = 1\n").is_err());
+        assert!(Answers::parse("[day06]\npart3 = 1\n").is_err());
+        assert!(Answers::parse("[day06]\npart1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(
+            compare(Ok("1".into()), Some("1")),
+            Outcome::Pass("1".into())
+        );
+        assert_eq!(
+            compare(Ok("2".into()), Some("1")),
+            Outcome::Fail {
+                expected: "1".into(),
+                actual: "2".into()
+            }
+        );
+        assert_eq!(compare(Ok("2".into()), None), Outcome::Missing("2".into()));
+        assert_eq!(
+            compare(Err(SolveError::NoSuchPart { day: 1, part: 3 }), Some("1")),
+            Outcome::Error("Day 1 has no part 3".into())
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let check = |outcome| Check {
+            day: 1,
+            part: 1,
+            outcome,
+        };
+        let checks = vec![
+            check(Outcome::Pass("1".into())),
+            check(Outcome::Missing("1".into())),
+            check(Outcome::Error("oops".into())),
+        ];
+        let summary = Summary::of(&checks);
+        assert_eq!(summary.to_string(), "1 passed, 1 failed, 1 missing");
+    }
+}
@@ -0,0 +1,38 @@
+use common::mode::prepare;
+use common::render::{Frame, Visualise};
+use common::SolveError;
+
+/// Parses an input and returns the frames of solving it.
+pub type Frames = fn(&str) -> Result<Box<dyn Iterator<Item = Frame>>, SolveError>;
+
+fn frames<V: Visualise>(input: &str) -> Result<Box<dyn Iterator<Item = Frame>>, SolveError> {
+    Ok(V::frames(V::parse(&prepare(input)?)?))
+}
+
+/// The days that can be visualised, being solved on a grid.
+pub const VISUALISED: &[(u8, Frames)] = &[
+    (6, frames::<day06::Day06>),
+    (8, frames::<day08::Day08>),
+    (10, frames::<day10::Day10>),
+    (12, frames::<day12::Day12>),
+];
+
+pub fn find(day: u8) -> Option<Frames> {
+    VISUALISED
+        .iter()
+        .find(|(number, _)| *number == day)
+        .map(|&(_, frames)| frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let frames = find(12).unwrap()("AB\nBB\n").unwrap();
+        assert_eq!(frames.count(), 2);
+        assert!(find(1).is_none());
+        assert!(find(6).unwrap()("...\n").is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use crate::bench::format_ns;
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// How long to let a burst of changes, such as an editor saving several
+/// files, settle before running again.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// One part's answer from a run of the day's binary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// The fields of the binaries' `--format json` output that are compared.
+#[derive(Deserialize)]
+struct Printed {
+    answer: String,
+    elapsed_ms: f64,
+}
+
+/// Reruns a day's tests and then its binaries on the real input whenever its
+/// sources, the shared `common` sources or the input change. The binaries
+/// are rebuilt with cargo since this one can't pick up changed code.
+pub struct Watch {
+    pub day: u8,
+    /// Root of the workspace.
+    pub root: PathBuf,
+    pub input: PathBuf,
+}
+
+impl Watch {
+    fn package(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    fn watched(&self) -> Vec<(PathBuf, RecursiveMode)> {
+        let mut watched = vec![
+            (self.root.join(self.package()), RecursiveMode::Recursive),
+            (
+                self.root.join("common").join("src"),
+                RecursiveMode::Recursive,
+            ),
+        ];
+        // Editors often replace a file rather than write to it, so watch the
+        // directory holding the input
+        if let Some(dir) = self.input.parent() {
+            if !watched.iter().any(|(path, _)| dir.starts_with(path)) {
+                watched.push((dir.to_path_buf(), RecursiveMode::NonRecursive));
+            }
+        }
+        watched
+    }
+
+    fn is_relevant(&self, path: &Path) -> bool {
+        if path.components().any(|c| c.as_os_str() == "target") {
+            return false;
+        }
+        path == self.input
+            || path.starts_with(self.root.join(self.package()))
+            || path.starts_with(self.root.join("common").join("src"))
+    }
+
+    /// Runs once, then again after every relevant change, until interrupted.
+    pub fn run(&self) -> Result<(), Box<dyn Error>> {
+        let (sender, changes) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        for (path, mode) in self.watched() {
+            watcher
+                .watch(&path, mode)
+                .map_err(|e| format!("Failed to watch {}: {}", path.display(), e))?;
+        }
+
+        let mut previous = Vec::new();
+        loop {
+            if let Some(answers) = self.run_once()? {
+                for line in report(&previous, &answers) {
+                    println!("{}", line);
+                }
+                previous = answers;
+            }
+            println!("Watching for changes to day {:02}...", self.day);
+
+            // Wait for a relevant change, then for the changes to settle
+            loop {
+                let event = changes.recv()??;
+                if matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) && event.paths.iter().any(|path| self.is_relevant(path))
+                {
+                    break;
+                }
+            }
+            while changes.recv_timeout(SETTLE).is_ok() {}
+            println!();
+        }
+    }
+
+    /// Runs the tests and, if they pass, both parts on the real input.
+    fn run_once(&self) -> Result<Option<Vec<Answer>>, Box<dyn Error>> {
+        println!("Testing {}...", self.package());
+        let tested = cargo(&self.root)
+            .args(["test", "--quiet", "--package", &self.package()])
+            .status()?;
+        if !tested.success() {
+            println!("Tests failed, not solving the real input");
+            return Ok(None);
+        }
+
+        let mut answers = Vec::new();
+        for part in 1..=2 {
+            match self.solve(part)? {
+                Ok(answer) => answers.push(answer),
+                Err(e) => {
+                    println!("Part {} failed: {}", part, e);
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(Some(answers))
+    }
+
+    fn solve(&self, part: u8) -> Result<Result<Answer, String>, Box<dyn Error>> {
+        let output = cargo(&self.root)
+            .args(["run", "--quiet", "--release", "--package", &self.package()])
+            .args(["--bin", &format!("{}-part{}", self.package(), part), "--"])
+            .arg("--format=json")
+            .arg(&self.input)
+            .output()?;
+        if !output.status.success() {
+            return Ok(Err(String::from_utf8_lossy(&output.stderr)
+                .trim()
+                .to_string()));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let printed: Printed = serde_json::from_str(stdout.lines().last().unwrap_or_default())
+            .map_err(|e| format!("Unexpected output from part {}: {}", part, e))?;
+        Ok(Ok(Answer {
+            part,
+            answer: printed.answer,
+            elapsed: Duration::from_secs_f64(printed.elapsed_ms / 1000.0),
+        }))
+    }
+}
+
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+/// A line per answer, saying how it and its timing differ from `previous`.
+pub fn report(previous: &[Answer], current: &[Answer]) -> Vec<String> {
+    current
+        .iter()
+        .map(|answer| {
+            let line = format!(
+                "Part {}: {} in {}",
+                answer.part,
+                answer.answer,
+                format_ns(answer.elapsed.as_nanos() as u64)
+            );
+            match previous.iter().find(|p| p.part == answer.part) {
+                None => line,
+                Some(before) => {
+                    let was = format_ns(before.elapsed.as_nanos() as u64);
+                    if before.answer == answer.answer {
+                        format!("{} (unchanged, was {})", line, was)
+                    } else {
+                        format!("{} (changed from {}, was {})", line, before.answer, was)
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(part: u8, answer: &str, ms: u64) -> Answer {
+        Answer {
+            part,
+            answer: answer.to_string(),
+            elapsed: Duration::from_millis(ms),
+        }
+    }
+
+    #[test]
+    fn test_report_compares_with_previous_run() {
+        let first = vec![answer(1, "11", 3), answer(2, "31", 5)];
+        assert_eq!(
+            report(&[], &first),
+            ["Part 1: 11 in 3.0ms", "Part 2: 31 in 5.0ms"]
+        );
+
+        let second = vec![answer(1, "11", 2), answer(2, "32", 5)];
+        assert_eq!(
+            report(&first, &second),
+            [
+                "Part 1: 11 in 2.0ms (unchanged, was 3.0ms)",
+                "Part 2: 32 in 5.0ms (changed from 31, was 5.0ms)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_relevant_changes() {
+        let watch = Watch {
+            day: 6,
+            root: PathBuf::from("/aoc"),
+            input: PathBuf::from("/inputs/day06.txt"),
+        };
+        assert!(watch.is_relevant(Path::new("/aoc/day06/src/part2.rs")));
+        assert!(watch.is_relevant(Path::new("/aoc/common/src/grid.rs")));
+        assert!(watch.is_relevant(Path::new("/inputs/day06.txt")));
+        assert!(!watch.is_relevant(Path::new("/inputs/day07.txt")));
+        assert!(!watch.is_relevant(Path::new("/aoc/day07/src/lib.rs")));
+        assert!(!watch.is_relevant(Path::new("/aoc/day06/target/debug/x")));
+        assert_eq!(watch.watched().len(), 3);
+    }
+}
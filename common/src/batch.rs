@@ -0,0 +1,164 @@
+use rayon::prelude::*;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Whether an input argument names several inputs, being a directory or a
+/// glob pattern such as `inputs/*/day06.txt`.
+pub fn is_batch(arg: &str) -> bool {
+    Path::new(arg).is_dir() || arg.contains(['*', '?', '['])
+}
+
+/// The files named by a batch argument in sorted order: those directly in a
+/// directory, or those matching a glob pattern.
+pub fn expand(arg: &str) -> Result<Vec<PathBuf>, String> {
+    let mut paths: Vec<PathBuf> = if Path::new(arg).is_dir() {
+        fs::read_dir(arg)
+            .map_err(|e| format!("Failed to list {}: {}", arg, e))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .collect()
+    } else {
+        glob::glob(arg)
+            .map_err(|e| format!("Invalid pattern {:?}: {}", arg, e))?
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect()
+    };
+    if paths.is_empty() {
+        return Err(format!("No inputs found in {}", arg));
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// The outcome of solving one input of a batch.
+#[derive(Debug)]
+pub struct Solved<T> {
+    pub path: PathBuf,
+    pub result: Result<T, String>,
+}
+
+/// Reads and solves each of `paths` in parallel, returning the outcomes in
+/// the same order.
+pub fn solve_all<T, F>(paths: &[PathBuf], solve: F) -> Vec<Solved<T>>
+where
+    T: Send,
+    F: Fn(&str) -> Result<T, String> + Sync,
+{
+    paths
+        .par_iter()
+        .map(|path| {
+            let result = match fs::read_to_string(path) {
+                Ok(input) => solve(&input),
+                Err(e) => Err(format!("Failed to read input: {}", e)),
+            };
+            Solved {
+                path: path.clone(),
+                result,
+            }
+        })
+        .collect()
+}
+
+/// Rows of text printed in aligned columns under a header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: ToString>(headers: &[S]) -> Table {
+        Table {
+            rows: vec![headers.iter().map(ToString::to_string).collect()],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let columns = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        for row in &self.rows {
+            let line: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{:width$}", cell))
+                .collect();
+            writeln!(f, "{}", line.join("  ").trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn test_expand_directory_and_glob() {
+        let dir = env::temp_dir().join(format!("aoc-batch-{}", process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        for name in ["bob.txt", "alice.txt", "notes.md", "nested/carol.txt"] {
+            fs::write(dir.join(name), "1   2\n").unwrap();
+        }
+        let dir_arg = dir.to_str().unwrap();
+
+        assert!(is_batch(dir_arg));
+        assert_eq!(
+            expand(dir_arg).unwrap(),
+            vec![
+                dir.join("alice.txt"),
+                dir.join("bob.txt"),
+                dir.join("notes.md")
+            ]
+        );
+        let pattern = format!("{}/**/*.txt", dir_arg);
+        assert!(is_batch(&pattern));
+        assert_eq!(expand(&pattern).unwrap().len(), 3);
+        assert!(expand(&format!("{}/*.json", dir_arg)).is_err());
+        assert!(!is_batch(dir.join("bob.txt").to_str().unwrap()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_solve_all_keeps_order_and_errors() {
+        let paths = vec![PathBuf::from("Cargo.toml"), PathBuf::from("missing.txt")];
+        let solved = solve_all(&paths, |input| Ok(input.lines().count()));
+        assert_eq!(solved[0].path, paths[0]);
+        assert!(solved[0].result.is_ok());
+        assert!(solved[1]
+            .result
+            .as_ref()
+            .unwrap_err()
+            .starts_with("Failed to read input"));
+    }
+
+    #[test]
+    fn test_table_aligns_columns() {
+        let mut table = Table::new(&["Input", "Part 1", "Time"]);
+        table.push(vec!["alice.txt".into(), "11".into(), "1ms".into()]);
+        table.push(vec!["b.txt".into(), "2000468".into(), "12ms".into()]);
+        assert_eq!(
+            table.to_string(),
+            "Input      Part 1   Time\nalice.txt  11       1ms\nb.txt      2000468  12ms\n"
+        );
+    }
+}
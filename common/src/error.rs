@@ -0,0 +1,191 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+/// Error returned when a day's puzzle input cannot be parsed.
+///
+/// Lines and columns are 1-based and columns count characters, so they match
+/// what an editor shows for the offending input.
+#[derive(Debug)]
+pub enum ParseError {
+    /// A token that should have been a number.
+    InvalidNumber {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+    /// A character that is not allowed where it appears.
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    /// A line, or part of one, that does not have the expected shape.
+    Malformed {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: &'static str,
+    },
+    /// A grid row whose length differs from the first row's.
+    RaggedRow {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// Something the input must contain was not found.
+    Missing(String),
+    /// The input could not be read at all.
+    Io(io::Error),
+}
+
+impl ParseError {
+    /// The line the error was found on, if it has one.
+    pub fn line(&self) -> Option<usize> {
+        match *self {
+            ParseError::InvalidNumber { line, .. }
+            | ParseError::UnexpectedChar { line, .. }
+            | ParseError::Malformed { line, .. }
+            | ParseError::RaggedRow { line, .. } => Some(line),
+            ParseError::Missing(_) | ParseError::Io(_) => None,
+        }
+    }
+
+    /// Moves an error found while parsing a single line, which is reported as
+    /// line 1, to `line` of the whole input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        match &mut self {
+            ParseError::InvalidNumber { line: l, .. }
+            | ParseError::UnexpectedChar { line: l, .. }
+            | ParseError::Malformed { line: l, .. }
+            | ParseError::RaggedRow { line: l, .. } => *l = line,
+            ParseError::Missing(_) | ParseError::Io(_) => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber { line, column, text } => write!(
+                f,
+                "Line {}, column {}: invalid number {:?}",
+                line, column, text
+            ),
+            ParseError::UnexpectedChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "Line {}, column {}: unexpected character {:?}",
+                line, column, found
+            ),
+            ParseError::Malformed {
+                line,
+                column,
+                text,
+                expected,
+            } => write!(
+                f,
+                "Line {}, column {}: expected {}, found {:?}",
+                line, column, expected, text
+            ),
+            ParseError::RaggedRow {
+                line,
+                width,
+                expected,
+            } => write!(
+                f,
+                "Line {}: row has {} cells but the first row has {}",
+                line, width, expected
+            ),
+            ParseError::Missing(what) => write!(f, "Missing {}", what),
+            ParseError::Io(e) => write!(f, "Failed to read input: {}", e),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(e: io::Error) -> Self {
+        ParseError::Io(e)
+    }
+}
+
+/// The 1-based character column at which `token` starts within `line`.
+///
+/// `token` must be a subslice of `line`, as returned by `split`, `trim` and
+/// friends.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    debug_assert!(offset <= line.len(), "token is not part of the line");
+    line[..offset.min(line.len())].chars().count() + 1
+}
+
+/// Parses `token`, a subslice of `line`, as a number, reporting where it was
+/// found if it is not one.
+pub fn parse_number<T: FromStr>(
+    line_number: usize,
+    line: &str,
+    token: &str,
+) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::InvalidNumber {
+        line: line_number,
+        column: column_of(line, token),
+        text: token.to_string(),
+    })
+}
+
+/// Whitespace separated tokens of `line`, parsed as numbers.
+pub fn parse_numbers<T: FromStr>(line_number: usize, line: &str) -> Result<Vec<T>, ParseError> {
+    line.split_whitespace()
+        .map(|token| parse_number(line_number, line, token))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_of() {
+        let line = "  12 345";
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        assert_eq!(column_of(line, tokens[0]), 3);
+        assert_eq!(column_of(line, tokens[1]), 6);
+        assert_eq!(column_of("ü 7", &"ü 7"[3..]), 3);
+    }
+
+    #[test]
+    fn test_parse_numbers_reports_position() {
+        assert_eq!(parse_numbers::<i32>(4, "1 2 3").unwrap(), vec![1, 2, 3]);
+
+        let error = parse_numbers::<i32>(4, "1 2x 3").unwrap_err();
+        assert_eq!(error.to_string(), "Line 4, column 3: invalid number \"2x\"");
+        assert_eq!(error.line(), Some(4));
+    }
+
+    #[test]
+    fn test_on_line() {
+        let error = parse_number::<u8>(1, "300", "300").unwrap_err().on_line(12);
+        assert_eq!(
+            error.to_string(),
+            "Line 12, column 1: invalid number \"300\""
+        );
+
+        let missing = ParseError::Missing("updates".to_string()).on_line(3);
+        assert_eq!(missing.line(), None);
+        assert_eq!(missing.to_string(), "Missing updates");
+    }
+}
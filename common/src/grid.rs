@@ -0,0 +1,474 @@
+use crate::mode::is_strict;
+use crate::ParseError;
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Sub};
+use std::str::FromStr;
+
+/// A cell position; `row` grows downwards and `col` grows to the right.
+/// Coordinates are signed so positions just outside a grid can be represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const fn new(row: i32, col: i32) -> Self {
+        Point { row, col }
+    }
+
+    /// The neighbouring position one step in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The four diagonal directions, clockwise from up-right.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::UpRight => Point::new(-1, 1),
+            Direction::Right => Point::new(0, 1),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(1, 0),
+            Direction::DownLeft => Point::new(1, -1),
+            Direction::Left => Point::new(0, -1),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotates 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        Self::ALL[(self as usize + 2) % 8]
+    }
+
+    /// Rotates 90 degrees anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        Self::ALL[(self as usize + 6) % 8]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (row, values) in rows.into_iter().enumerate() {
+            if values.len() != width {
+                return Err(ParseError::RaggedRow {
+                    line: row + 1,
+                    width: values.len(),
+                    expected: width,
+                });
+            }
+            cells.extend(values);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a grid of characters, converting each one with `parse_cell`,
+    /// which returns `None` for characters that are not allowed. Blank lines
+    /// are skipped unless parsing is strict.
+    pub fn parse_with<F>(input: &str, mut parse_cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let strict = is_strict();
+        let mut rows: Vec<Vec<T>> = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() && !strict {
+                continue;
+            }
+
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(col, c)| {
+                    parse_cell(c).ok_or(ParseError::UnexpectedChar {
+                        line: i + 1,
+                        column: col + 1,
+                        found: c,
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::RaggedRow {
+                        line: i + 1,
+                        width: row.len(),
+                        expected: first.len(),
+                    });
+                }
+            }
+            rows.push(row);
+        }
+
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row >= 0
+            && (point.row as usize) < self.height
+            && point.col >= 0
+            && (point.col as usize) < self.width
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.row as usize * self.width + point.col as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |row| (0..width).map(move |col| Point::new(row as i32, col as i32)))
+    }
+
+    /// Every cell alongside its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Positions of the cells matching `predicate`, row by row.
+    pub fn positions<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = Point> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    pub fn find<P>(&self, predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.positions(predicate).next()
+    }
+
+    /// The in-bounds neighbours of `point` in the given directions.
+    pub fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .map(move |&direction| point.step(direction))
+            .filter(move |&next| self.contains(next))
+    }
+
+    /// The up to four orthogonally adjacent positions of `point`.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &Direction::ORTHOGONAL)
+    }
+
+    /// The up to eight adjacent positions of `point`, including diagonals.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &Direction::ALL)
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.cells[row * self.width..(row + 1) * self.width].iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy for a grid with no columns
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    /// Cells from `start` outwards in `direction` until the edge of the grid,
+    /// e.g. a diagonal when `direction` is one of [`Direction::DIAGONAL`].
+    pub fn ray(&self, start: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        let delta = direction.delta();
+        std::iter::successors(Some(start), move |&point| Some(point + delta))
+            .map_while(move |point| self.get(point).map(|value| (point, value)))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(value) => value,
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                point, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(value) => value,
+            None => panic!("{:?} is outside the {}x{} grid", point, width, height),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters, rejecting any that aren't `allowed` when
+    /// parsing is strict.
+    pub fn parse_chars(input: &str, allowed: fn(char) -> bool) -> Result<Self, ParseError> {
+        let strict = is_strict();
+        Grid::parse_with(input, |c| (!strict || allowed(c)).then_some(c))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Some)
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_ragged_rows_are_rejected() {
+        let error = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2: row has 2 cells but the first row has 3"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_reports_position() {
+        let error = Grid::parse_with("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 2: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn test_blank_lines_depend_on_mode() {
+        use crate::mode::{with_parse_mode, ParseMode};
+
+        let input = "ab\n\ncd\n";
+        let grid = with_parse_mode(ParseMode::Lenient, || input.parse::<Grid<char>>()).unwrap();
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+
+        let error = with_parse_mode(ParseMode::Strict, || input.parse::<Grid<char>>()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2: row has 0 cells but the first row has 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_chars_is_only_picky_when_strict() {
+        use crate::mode::{with_parse_mode, ParseMode};
+
+        let allowed = |c| c == '.' || c == '#';
+        assert!(with_parse_mode(ParseMode::Lenient, || Grid::parse_chars(
+            ".#\n.x\n", allowed
+        ))
+        .is_ok());
+        let error = with_parse_mode(ParseMode::Strict, || Grid::parse_chars(".#\n.x\n", allowed))
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 2: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let grid: Grid<char> = "".parse().unwrap();
+        assert!(grid.is_empty());
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Point::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_columns_and_rays() {
+        let grid = example();
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        let diagonal: String = grid
+            .ray(Point::new(0, 0), Direction::DownRight)
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(diagonal, "ae");
+    }
+
+    #[test]
+    fn test_find_and_map() {
+        let grid = example();
+        assert_eq!(grid.find(|&c| c == 'e'), Some(Point::new(1, 1)));
+        let upper = grid.map(|c| c.to_ascii_uppercase());
+        assert_eq!(upper.to_string(), "ABC\nDEF\n");
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::mode::prepare;
+use crate::output::Args;
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Environment variable naming a directory of puzzle inputs, laid out either
+/// like this repository (`day06/input.txt`) or flat (`day06.txt`).
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the input for `day`: an explicit argument (`-` for stdin) wins,
+    /// then a file under `$AOC_INPUT_DIR`, then `default`.
+    pub fn resolve(arg: Option<&str>, day: u8, default: impl Into<PathBuf>) -> InputSource {
+        let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        Self::resolve_with(arg, day, default.into(), input_dir.as_deref())
+    }
+
+    fn resolve_with(
+        arg: Option<&str>,
+        day: u8,
+        default: PathBuf,
+        input_dir: Option<&Path>,
+    ) -> InputSource {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(PathBuf::from(path)),
+            None => match input_dir {
+                Some(dir) => InputSource::Path(find_in_dir(dir, day)),
+                None => InputSource::Path(default),
+            },
+        }
+    }
+
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::Path(path) => File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|e| self.error(e)),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    pub fn read_to_string(&self) -> Result<String, InputError> {
+        let mut input = String::new();
+        self.open()?
+            .read_to_string(&mut input)
+            .map_err(|e| self.error(e))?;
+        Ok(input)
+    }
+
+    fn error(&self, source: io::Error) -> InputError {
+        InputError {
+            input: self.clone(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+fn find_in_dir(dir: &Path, day: u8) -> PathBuf {
+    let nested = dir.join(format!("day{:02}", day)).join("input.txt");
+    if nested.is_file() {
+        nested
+    } else {
+        dir.join(format!("day{:02}.txt", day))
+    }
+}
+
+/// Failure to read a day's puzzle input.
+#[derive(Debug)]
+pub struct InputError {
+    pub input: InputSource,
+    pub source: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Failed to read input from {}: {}",
+            self.input, self.source
+        )
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Resolves the input named on the command line of a day's binary,
+/// defaulting to `input.txt` in the working directory.
+pub fn source_from_args(day: u8) -> InputSource {
+    Args::from_env().source(day)
+}
+
+/// Reads the input for a day's binary and prepares it in the current parse
+/// mode, exiting with a message if it is missing or malformed.
+pub fn read_from_args(day: u8) -> String {
+    let source = source_from_args(day);
+    let input = source.read_to_string().unwrap_or_else(|e| exit_with(e));
+    match prepare(&input) {
+        Ok(prepared) => prepared.into_owned(),
+        Err(e) => exit_with(format!("{}: {}", source, e)),
+    }
+}
+
+fn exit_with(error: impl fmt::Display) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_explicit_argument_wins() {
+        let source = InputSource::resolve_with(
+            Some("other.txt"),
+            6,
+            PathBuf::from("input.txt"),
+            Some(Path::new("/inputs")),
+        );
+        assert_eq!(source, InputSource::Path(PathBuf::from("other.txt")));
+    }
+
+    #[test]
+    fn test_dash_means_stdin() {
+        let source = InputSource::resolve_with(Some("-"), 6, PathBuf::from("input.txt"), None);
+        assert_eq!(source, InputSource::Stdin);
+        assert_eq!(source.to_string(), "<stdin>");
+    }
+
+    #[test]
+    fn test_default_without_input_dir() {
+        let source = InputSource::resolve_with(None, 6, PathBuf::from("input.txt"), None);
+        assert_eq!(source, InputSource::Path(PathBuf::from("input.txt")));
+    }
+
+    #[test]
+    fn test_input_dir_layouts() {
+        let dir = temp_dir("layouts");
+        let flat = InputSource::resolve_with(None, 6, PathBuf::from("input.txt"), Some(&dir));
+        assert_eq!(flat, InputSource::Path(dir.join("day06.txt")));
+
+        fs::create_dir_all(dir.join("day06")).unwrap();
+        fs::write(dir.join("day06").join("input.txt"), "....#\n").unwrap();
+        let nested = InputSource::resolve_with(None, 6, PathBuf::from("input.txt"), Some(&dir));
+        assert_eq!(
+            nested,
+            InputSource::Path(dir.join("day06").join("input.txt"))
+        );
+        assert_eq!(nested.read_to_string().unwrap(), "....#\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_file_error_names_path() {
+        let source = InputSource::Path(PathBuf::from("does/not/exist.txt"));
+        let error = source.read_to_string().unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Failed to read input from does/not/exist.txt"));
+    }
+}
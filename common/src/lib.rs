@@ -0,0 +1,13 @@
+pub mod batch;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod mode;
+pub mod output;
+pub mod render;
+pub mod solution;
+
+pub use error::ParseError;
+pub use grid::{Direction, Grid, Point};
+pub use solution::{solve, Solution, SolveError};
@@ -0,0 +1,121 @@
+use log::{LevelFilter, Log, Metadata, Record};
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Environment variable choosing the log level of the day binaries, see [`Level`].
+pub const LOG_LEVEL_VAR: &str = "AOC_LOG";
+
+/// How much a solver says about its work. Diagnostics go to stderr, so the
+/// answer on stdout stands alone at every level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Level {
+    /// Only warnings and errors.
+    #[default]
+    Quiet,
+    /// A summary line or two per part.
+    Info,
+    /// A line per item processed.
+    Debug,
+    /// Everything, including the details of each item.
+    Trace,
+}
+
+impl Level {
+    pub fn filter(self) -> LevelFilter {
+        match self {
+            Level::Quiet => LevelFilter::Warn,
+            Level::Info => LevelFilter::Info,
+            Level::Debug => LevelFilter::Debug,
+            Level::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "quiet" => Ok(Level::Quiet),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "Unknown log level {:?}, expected quiet, info, debug or trace",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Level::Quiet => write!(f, "quiet"),
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+/// The level named by `$AOC_LOG`, or quiet if it is unset or invalid.
+pub fn level_from_env() -> Level {
+    env::var(LOG_LEVEL_VAR)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let _ = writeln!(
+                io::stderr().lock(),
+                "[{} {}] {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Sends log messages at `level` and above to stderr. Calling it again only
+/// changes the level.
+pub fn init(level: Level) {
+    // Fails only if a logger is already installed, which is then kept
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level.filter());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_names() {
+        for level in [Level::Quiet, Level::Info, Level::Debug, Level::Trace] {
+            assert_eq!(level.to_string().parse(), Ok(level));
+        }
+        assert!("verbose".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn test_quiet_still_warns() {
+        assert_eq!(Level::Quiet.filter(), LevelFilter::Warn);
+        assert!(Level::Trace.filter() > Level::Debug.filter());
+    }
+}
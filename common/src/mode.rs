@@ -0,0 +1,286 @@
+use crate::error::ParseError;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Environment variable choosing the parse mode of the day binaries,
+/// `strict` or `lenient`.
+pub const PARSE_MODE_VAR: &str = "AOC_PARSE_MODE";
+
+/// How forgiving parsers are of hand-edited input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Normalise line endings and whitespace, skipping stray blank lines.
+    #[default]
+    Lenient,
+    /// Reject anything unexpected with a diagnostic.
+    Strict,
+}
+
+impl FromStr for ParseMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lenient" => Ok(ParseMode::Lenient),
+            "strict" => Ok(ParseMode::Strict),
+            _ => Err(format!(
+                "Unknown parse mode {:?}, expected strict or lenient",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ParseMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseMode::Lenient => write!(f, "lenient"),
+            ParseMode::Strict => write!(f, "strict"),
+        }
+    }
+}
+
+// 0 until the mode is set or first read from the environment
+static MODE: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    static OVERRIDE: Cell<Option<ParseMode>> = const { Cell::new(None) };
+}
+
+fn encode(mode: ParseMode) -> u8 {
+    match mode {
+        ParseMode::Lenient => 1,
+        ParseMode::Strict => 2,
+    }
+}
+
+/// Sets the parse mode used by every parser in this process.
+pub fn set_parse_mode(mode: ParseMode) {
+    MODE.store(encode(mode), Ordering::Relaxed);
+}
+
+/// Runs `f` with `mode` in effect on this thread only, e.g. in tests.
+pub fn with_parse_mode<R>(mode: ParseMode, f: impl FnOnce() -> R) -> R {
+    let previous = OVERRIDE.replace(Some(mode));
+    let result = f();
+    OVERRIDE.set(previous);
+    result
+}
+
+/// The current parse mode: whatever was last set, otherwise `$AOC_PARSE_MODE`,
+/// otherwise lenient.
+pub fn parse_mode() -> ParseMode {
+    if let Some(mode) = OVERRIDE.get() {
+        return mode;
+    }
+    match MODE.load(Ordering::Relaxed) {
+        1 => ParseMode::Lenient,
+        2 => ParseMode::Strict,
+        _ => {
+            let mode = env::var(PARSE_MODE_VAR)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default();
+            // Don't overwrite a mode set by another thread in the meantime
+            let _ = MODE.compare_exchange(0, encode(mode), Ordering::Relaxed, Ordering::Relaxed);
+            mode
+        }
+    }
+}
+
+pub fn is_strict() -> bool {
+    parse_mode() == ParseMode::Strict
+}
+
+/// Applies the current parse mode to a whole puzzle input before it is parsed.
+pub fn prepare(input: &str) -> Result<Cow<'_, str>, ParseError> {
+    match parse_mode() {
+        ParseMode::Lenient => Ok(normalise(input)),
+        ParseMode::Strict => validate(input).map(|()| Cow::Borrowed(input)),
+    }
+}
+
+/// Converts CRLF line endings, strips trailing whitespace from every line and
+/// drops blank lines at the end. Lines keep their numbers so errors still
+/// point at the right place in the original file.
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    let is_clean = !input.contains('\r')
+        && input
+            .lines()
+            .all(|line| line.trim_end().len() == line.len())
+        && !input.ends_with("\n\n");
+    if is_clean {
+        return Cow::Borrowed(input);
+    }
+
+    let mut output = String::with_capacity(input.len());
+    for line in input.lines() {
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    let content = output.trim_end_matches('\n').len();
+    output.truncate(content);
+    if content > 0 {
+        output.push('\n');
+    }
+    Cow::Owned(output)
+}
+
+/// Checks `input` has Unix line endings, no trailing whitespace, no blank
+/// lines at the start or end and no more than one blank line in a row.
+pub fn validate(input: &str) -> Result<(), ParseError> {
+    let lines: Vec<&str> = input.split('\n').collect();
+    // A final newline leaves an empty last element, which is fine
+    let last = match lines.last() {
+        Some(&"") => lines.len() - 1,
+        _ => lines.len(),
+    };
+
+    for (i, line) in lines[..last].iter().enumerate() {
+        let line_number = i + 1;
+        validate_line(line_number, line)?;
+
+        if line.is_empty() {
+            let problem = if i == 0 {
+                Some("no blank lines at the start of the input")
+            } else if i + 1 == last {
+                Some("no blank lines at the end of the input")
+            } else if lines[i - 1].is_empty() {
+                Some("at most one blank line in a row")
+            } else {
+                None
+            };
+            if let Some(expected) = problem {
+                return Err(ParseError::Malformed {
+                    line: line_number,
+                    column: 1,
+                    text: String::new(),
+                    expected,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks one line, without its `\n`, has no carriage return or trailing
+/// whitespace. For input read a line at a time, where [`validate`] can't be
+/// used; blank lines are left to the caller.
+pub fn validate_line(line_number: usize, line: &str) -> Result<(), ParseError> {
+    if let Some(column) = line.find('\r') {
+        return Err(ParseError::UnexpectedChar {
+            line: line_number,
+            column: line[..column].chars().count() + 1,
+            found: '\r',
+        });
+    }
+
+    let trimmed = line.trim_end();
+    if trimmed.len() != line.len() {
+        return Err(ParseError::UnexpectedChar {
+            line: line_number,
+            column: trimmed.chars().count() + 1,
+            found: line[trimmed.len()..].chars().next().unwrap(),
+        });
+    }
+    Ok(())
+}
+
+/// Non-blank lines of `input` with their 1-based line numbers. Blank lines
+/// are skipped when lenient and are an error when strict.
+pub fn content_lines(input: &str) -> impl Iterator<Item = Result<(usize, &str), ParseError>> {
+    let strict = is_strict();
+    input
+        .lines()
+        .enumerate()
+        .filter_map(move |(i, line)| match (line.trim().is_empty(), strict) {
+            (false, _) => Some(Ok((i + 1, line))),
+            (true, false) => None,
+            (true, true) => Some(Err(ParseError::Malformed {
+                line: i + 1,
+                column: 1,
+                text: line.to_string(),
+                expected: "no blank lines",
+            })),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(normalise("ab\ncd\n"), Cow::Borrowed("ab\ncd\n"));
+        assert_eq!(normalise("ab \r\n\ncd\t\r\n\r\n\n"), "ab\n\ncd\n");
+        assert_eq!(normalise("\n\n"), "");
+    }
+
+    #[test]
+    fn test_validate_accepts_clean_input() {
+        assert!(validate("ab\n\ncd\n").is_ok());
+        assert!(validate("ab\ncd").is_ok());
+        assert!(validate("").is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_untidy_input() {
+        let message = |input| validate(input).unwrap_err().to_string();
+        assert_eq!(
+            message("ab\r\ncd\r\n"),
+            "Line 1, column 3: unexpected character '\\r'"
+        );
+        assert_eq!(
+            message("ab\ncd  \n"),
+            "Line 2, column 3: unexpected character ' '"
+        );
+        assert_eq!(
+            message("\nab\n"),
+            "Line 1, column 1: expected no blank lines at the start of the input, found \"\""
+        );
+        assert_eq!(
+            message("ab\n\n\ncd\n"),
+            "Line 3, column 1: expected at most one blank line in a row, found \"\""
+        );
+        assert_eq!(
+            message("ab\n\n"),
+            "Line 2, column 1: expected no blank lines at the end of the input, found \"\""
+        );
+    }
+
+    #[test]
+    fn test_prepare_follows_mode() {
+        let untidy = "ab \r\n";
+        let lenient = with_parse_mode(ParseMode::Lenient, || prepare(untidy).unwrap());
+        assert_eq!(lenient, "ab\n");
+        assert!(with_parse_mode(ParseMode::Strict, || prepare(untidy)).is_err());
+    }
+
+    #[test]
+    fn test_content_lines() {
+        let input = "a\n\nb\n";
+        let lines: Vec<_> = with_parse_mode(ParseMode::Lenient, || {
+            content_lines(input).collect::<Result<_, _>>().unwrap()
+        });
+        assert_eq!(lines, vec![(1, "a"), (3, "b")]);
+
+        let error = with_parse_mode(ParseMode::Strict, || {
+            content_lines(input)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_err()
+        });
+        assert_eq!(error.line(), Some(2));
+    }
+
+    #[test]
+    fn test_parse_mode_names() {
+        assert_eq!("strict".parse(), Ok(ParseMode::Strict));
+        assert_eq!(ParseMode::Lenient.to_string(), "lenient");
+        assert!("loose".parse::<ParseMode>().is_err());
+    }
+}
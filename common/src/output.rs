@@ -0,0 +1,447 @@
+use crate::batch::{self, Table};
+use crate::input::InputSource;
+use crate::logging::{self, Level};
+use crate::mode::prepare;
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::any::Any;
+use std::env;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// How a solver reports its answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// A sentence per answer, worded by each day.
+    #[default]
+    Text,
+    /// One JSON object per answer, see [`Record::to_json`].
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format {:?}, expected text or json", s)),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// Command line arguments shared by the day binaries: an optional input path
+/// (`-` for stdin, or a directory or glob pattern of several inputs),
+/// `--format text|json` and `--log-level`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Args {
+    pub input: Option<String>,
+    pub format: Format,
+    /// Overrides `$AOC_LOG` when given.
+    pub log_level: Option<Level>,
+}
+
+impl Args {
+    /// Parses the process arguments, exiting with a usage message if they are
+    /// invalid, and starts logging at the requested level.
+    pub fn from_env() -> Args {
+        let args = Self::parse(env::args().skip(1)).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            eprintln!(
+                "Usage: [--format text|json] [--log-level quiet|info|debug|trace] [INPUT | - | DIR | GLOB]"
+            );
+            process::exit(2);
+        });
+        logging::init(args.log_level.unwrap_or_else(logging::level_from_env));
+        args
+    }
+
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if let Some(format) = arg.strip_prefix("--format=") {
+                parsed.format = format.parse()?;
+            } else if arg == "--format" {
+                let format = args.next().ok_or("--format needs a value")?;
+                parsed.format = format.parse()?;
+            } else if let Some(level) = arg.strip_prefix("--log-level=") {
+                parsed.log_level = Some(level.parse()?);
+            } else if arg == "--log-level" {
+                let level = args.next().ok_or("--log-level needs a value")?;
+                parsed.log_level = Some(level.parse()?);
+            } else if arg.starts_with("--") {
+                return Err(format!("Unknown option {}", arg));
+            } else if parsed.input.replace(arg).is_some() {
+                return Err("Only one input may be given".to_string());
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// The input argument if it names several inputs, see [`batch::is_batch`].
+    pub fn batch(&self) -> Option<&str> {
+        self.input.as_deref().filter(|arg| batch::is_batch(arg))
+    }
+
+    /// Where to read `day`'s input from, see [`InputSource::resolve`].
+    pub fn source(&self, day: u8) -> InputSource {
+        InputSource::resolve(self.input.as_deref(), day, "input.txt")
+    }
+}
+
+/// The outcome of solving one part, for machine-readable output. The answer
+/// is a string since some answers are too large for JSON numbers to hold
+/// exactly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(rename = "elapsed_ms", serialize_with = "milliseconds")]
+    pub elapsed: Duration,
+    /// SHA-256 of the raw input, as printed by `sha256sum`.
+    pub input_hash: String,
+    /// False when `answer` is only an estimate. Written to JSON only then.
+    #[serde(skip_serializing_if = "is_true")]
+    pub exact: bool,
+}
+
+fn is_true(exact: &bool) -> bool {
+    *exact
+}
+
+/// Durations in JSON are milliseconds to the nearest microsecond.
+fn milliseconds<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64((elapsed.as_secs_f64() * 1_000_000.0).round() / 1000.0)
+}
+
+/// A record along with the path of the input solved, for batches.
+#[derive(Serialize)]
+struct WithInput<'a> {
+    input: &'a str,
+    #[serde(flatten)]
+    record: &'a Record,
+}
+
+impl Record {
+    /// The record as a single line JSON object.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records always serialise")
+    }
+
+    /// [`Record::to_json`] with the path of the input solved, for batches.
+    pub fn to_json_with_input(&self, input: &str) -> String {
+        serde_json::to_string(&WithInput {
+            input,
+            record: self,
+        })
+        .expect("records always serialise")
+    }
+}
+
+/// Hex encoded SHA-256 of `input`.
+pub fn input_hash(input: impl AsRef<[u8]>) -> String {
+    hex(&Sha256::digest(input.as_ref()))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hashes everything read through it, for solvers that stream their input.
+pub struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> HashingReader<R> {
+    pub fn new(inner: R) -> Self {
+        HashingReader {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// The hash of everything read so far.
+    pub fn hash(&self) -> String {
+        hex(&self.hasher.clone().finalize())
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Prints `record` in `format`, using `text` for the human readable sentence.
+pub fn emit(format: Format, record: &Record, text: impl FnOnce(&str) -> String) {
+    match format {
+        Format::Text => println!("{}", text(&record.answer)),
+        Format::Json => println!("{}", record.to_json()),
+    }
+}
+
+/// Runs one part of a day as a binary: reads the input named on the command
+/// line, times `solve` on it and prints the answer as `describe` words it, or
+/// as JSON with `--format json`. Several inputs are solved with [`run_batch`].
+pub fn run_part<A, S>(day: u8, part: u8, solve: S, describe: impl FnOnce(&str) -> String)
+where
+    A: Display,
+    S: Fn(&str) -> A + Sync,
+{
+    let args = Args::from_env();
+    if let Some(arg) = args.batch() {
+        return run_batch(day, part, arg, args.format, |input| {
+            Ok(solve(input).to_string())
+        });
+    }
+    let source = args.source(day);
+    let raw = source.read_to_string().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let input = prepare(&raw).unwrap_or_else(|e| {
+        eprintln!("{}: {}", source, e);
+        process::exit(1);
+    });
+
+    let start = Instant::now();
+    let answer = solve(&input).to_string();
+    let record = Record {
+        day,
+        part,
+        answer,
+        elapsed: start.elapsed(),
+        input_hash: input_hash(&raw),
+        exact: true,
+    };
+
+    emit(args.format, &record, describe);
+}
+
+/// Solves one part of a day for each input named by the batch argument
+/// `arg` with [`solve_batch`], exiting with an error if any input could not
+/// be solved.
+pub fn run_batch<S>(day: u8, part: u8, arg: &str, format: Format, solve: S)
+where
+    S: Fn(&str) -> Result<String, String> + Sync,
+{
+    if let Err(e) = solve_batch(day, &[part], arg, format, |_, input| solve(input)) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+/// Solves `parts` of a day for each input named by the batch argument `arg`
+/// in parallel, printing a table of the answers per input, or a JSON record
+/// per part and input. Each part is timed on its own. Errs if any input
+/// could not be solved, after printing the answers for the rest.
+pub fn solve_batch<S>(
+    day: u8,
+    parts: &[u8],
+    arg: &str,
+    format: Format,
+    solve: S,
+) -> Result<(), String>
+where
+    S: Fn(u8, &str) -> Result<String, String> + Sync,
+{
+    let paths = batch::expand(arg)?;
+    let solved = batch::solve_all(&paths, |raw| solve_parts(raw, parts, &solve));
+
+    let mut headers = vec!["Input".to_string()];
+    for part in parts {
+        headers.push(format!("Part {}", part));
+        headers.push("Time".to_string());
+    }
+    let mut table = Table::new(&headers);
+    let mut failed = 0;
+    for solved in solved {
+        let path = solved.path.display().to_string();
+        match (solved.result, format) {
+            (Ok((answers, input_hash)), Format::Json) => {
+                for (&part, (answer, elapsed)) in parts.iter().zip(answers) {
+                    let record = Record {
+                        day,
+                        part,
+                        answer,
+                        elapsed,
+                        input_hash: input_hash.clone(),
+                        exact: true,
+                    };
+                    println!("{}", record.to_json_with_input(&path));
+                }
+            }
+            (Ok((answers, _)), Format::Text) => {
+                let mut row = vec![path];
+                for (answer, elapsed) in answers {
+                    row.push(answer);
+                    row.push(format!("{:.2?}", elapsed));
+                }
+                table.push(row);
+            }
+            (Err(e), Format::Json) => {
+                failed += 1;
+                eprintln!("{}: {}", path, e);
+            }
+            (Err(e), Format::Text) => {
+                failed += 1;
+                table.push(vec![path, format!("error: {}", e)]);
+            }
+        }
+    }
+
+    if format == Format::Text {
+        print!("{}", table);
+    }
+    if failed > 0 {
+        return Err(format!(
+            "{} of {} inputs could not be solved",
+            failed,
+            paths.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Each of `parts` of one raw input, with the time each took, and the
+/// input's hash. One bad input shouldn't lose the answers for the rest of a
+/// batch, so a part that panics is an error like any other.
+fn solve_parts<S>(
+    raw: &str,
+    parts: &[u8],
+    solve: &S,
+) -> Result<(Vec<(String, Duration)>, String), String>
+where
+    S: Fn(u8, &str) -> Result<String, String>,
+{
+    let input = prepare(raw).map_err(|e| e.to_string())?;
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = panic::catch_unwind(AssertUnwindSafe(|| solve(part, &input)))
+                .map_err(|payload| format!("panicked: {}", panic_message(&*payload)))
+                .and_then(|answer| answer)
+                .map_err(|e| format!("part {}: {}", part, e))?;
+            Ok((answer, start.elapsed()))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok((answers, input_hash(raw)))
+}
+
+/// The message a panic was raised with, if it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or("no message")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Result<Args, String> {
+        Args::parse(list.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_args() {
+        assert_eq!(args(&[]).unwrap(), Args::default());
+        assert_eq!(
+            args(&["--format", "json", "in.txt"]).unwrap(),
+            Args {
+                input: Some("in.txt".to_string()),
+                format: Format::Json,
+                log_level: None,
+            }
+        );
+        assert_eq!(
+            args(&["--log-level=debug"]).unwrap().log_level,
+            Some(Level::Debug)
+        );
+        assert_eq!(
+            args(&["-", "--format=text"]).unwrap().input.as_deref(),
+            Some("-")
+        );
+        assert!(args(&["--format", "xml"]).is_err());
+        assert!(args(&["--format"]).is_err());
+        assert!(args(&["--log-level", "loud"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_record_json() {
+        let record = Record {
+            day: 6,
+            part: 2,
+            answer: "1688".to_string(),
+            elapsed: Duration::from_micros(12_345),
+            input_hash: input_hash(""),
+            exact: true,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":6,"part":2,"answer":"1688","elapsed_ms":12.345,"input_hash":"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"}"#
+        );
+        let quoted = Record {
+            answer: "a\"b\\\n".to_string(),
+            ..record.clone()
+        };
+        assert!(quoted.to_json().contains(r#""answer":"a\"b\\\n""#));
+        let estimate = Record {
+            exact: false,
+            ..record.clone()
+        };
+        assert!(estimate.to_json().ends_with(r#","exact":false}"#));
+        assert!(record
+            .to_json_with_input("inputs/alice.txt")
+            .starts_with(r#"{"input":"inputs/alice.txt","day":6,"#));
+    }
+
+    #[test]
+    fn test_panicking_parts_are_errors() {
+        let solved = solve_parts("1\n", &[1, 2], &|part, input| {
+            if part == 2 {
+                panic!("no part {}", part);
+            }
+            Ok(input.trim().to_string())
+        });
+        assert_eq!(solved.unwrap_err(), "part 2: panicked: no part 2");
+
+        let (answers, hash) = solve_parts("1\n", &[1], &|_, _| Ok("one".to_string())).unwrap();
+        assert_eq!(answers[0].0, "one");
+        assert_eq!(hash, input_hash("1\n"));
+    }
+
+    #[test]
+    fn test_hashing_reader_matches_input_hash() {
+        let mut reader = HashingReader::new("3   4\n4   3\n".as_bytes());
+        let mut text = String::new();
+        reader.read_to_string(&mut text).unwrap();
+        assert_eq!(reader.hash(), input_hash(&text));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{Direction, Grid, Point, Solution};
+use std::fmt;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// A 24-bit colour, drawn on terminals with ANSI escapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const RED: Colour = Colour::rgb(230, 60, 60);
+    pub const GREEN: Colour = Colour::rgb(80, 200, 90);
+    pub const YELLOW: Colour = Colour::rgb(240, 200, 50);
+    pub const BLUE: Colour = Colour::rgb(70, 130, 230);
+    pub const CYAN: Colour = Colour::rgb(60, 200, 210);
+    pub const GREY: Colour = Colour::rgb(120, 120, 120);
+    pub const WHITE: Colour = Colour::rgb(230, 230, 230);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Colour {
+        Colour { r, g, b }
+    }
+
+    /// One of a dozen distinct colours, for telling apart things such as
+    /// regions. Indices past the end wrap around.
+    pub fn palette(index: usize) -> Colour {
+        PALETTE[index % PALETTE.len()]
+    }
+}
+
+const PALETTE: [Colour; 12] = [
+    Colour::rgb(228, 26, 28),
+    Colour::rgb(55, 126, 184),
+    Colour::rgb(77, 175, 74),
+    Colour::rgb(152, 78, 163),
+    Colour::rgb(255, 127, 0),
+    Colour::rgb(200, 200, 51),
+    Colour::rgb(166, 86, 40),
+    Colour::rgb(247, 129, 191),
+    Colour::rgb(27, 158, 119),
+    Colour::rgb(117, 112, 179),
+    Colour::rgb(102, 166, 30),
+    Colour::rgb(230, 171, 2),
+];
+
+/// One cell of a [`Frame`]: a character with optional colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Option<Colour>,
+    pub bg: Option<Colour>,
+}
+
+impl Cell {
+    fn ansi_style(&self) -> String {
+        let mut style = String::new();
+        if let Some(Colour { r, g, b }) = self.fg {
+            style.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+        }
+        if let Some(Colour { r, g, b }) = self.bg {
+            style.push_str(&format!("\x1b[48;2;{};{};{}m", r, g, b));
+        }
+        style
+    }
+}
+
+/// A picture of a grid puzzle part way through being solved: the map with
+/// overlays drawn over it, and a caption saying what is shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    /// Marks on a corner of a cell, which fall between characters and so
+    /// are only drawn in images.
+    pub corners: Vec<(Point, Direction, Colour)>,
+    pub caption: String,
+}
+
+impl Frame {
+    /// An uncoloured picture of `map`, drawing each tile as `glyph` has it.
+    pub fn new<T>(map: &Grid<T>, mut glyph: impl FnMut(&T) -> char) -> Frame {
+        Frame {
+            cells: map.map(|tile| Cell {
+                glyph: glyph(tile),
+                fg: None,
+                bg: None,
+            }),
+            corners: Vec::new(),
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Frame {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Draws `points` in `colour`, replacing their character with `glyph` if
+    /// one is given. Points off the map are ignored.
+    pub fn overlay(
+        &mut self,
+        points: impl IntoIterator<Item = Point>,
+        glyph: Option<char>,
+        colour: Colour,
+    ) {
+        for point in points {
+            if let Some(cell) = self.cells.get_mut(point) {
+                cell.glyph = glyph.unwrap_or(cell.glyph);
+                cell.fg = Some(colour);
+            }
+        }
+    }
+
+    /// Fills the background of `points` with `colour`.
+    pub fn highlight(&mut self, points: impl IntoIterator<Item = Point>, colour: Colour) {
+        for point in points {
+            if let Some(cell) = self.cells.get_mut(point) {
+                cell.bg = Some(colour);
+            }
+        }
+    }
+
+    /// Marks the `corner` of `point`, given as a diagonal direction.
+    pub fn mark_corner(&mut self, point: Point, corner: Direction, colour: Colour) {
+        self.corners.push((point, corner, colour));
+    }
+
+    /// The frame with its colours as ANSI escapes, for printing to a terminal.
+    pub fn to_ansi(&self) -> String {
+        let mut ansi = String::new();
+        for row in self.cells.rows() {
+            let mut style = String::new();
+            for cell in row {
+                let cell_style = cell.ansi_style();
+                if cell_style != style {
+                    if !style.is_empty() {
+                        ansi.push_str(RESET);
+                    }
+                    ansi.push_str(&cell_style);
+                    style = cell_style;
+                }
+                ansi.push(cell.glyph);
+            }
+            if !style.is_empty() {
+                ansi.push_str(RESET);
+            }
+            ansi.push('\n');
+        }
+        if !self.caption.is_empty() {
+            ansi.push_str(&self.caption);
+            ansi.push('\n');
+        }
+        ansi
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// The frame without colours.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.rows() {
+            writeln!(
+                f,
+                "{}",
+                row.iter().map(|cell| cell.glyph).collect::<String>()
+            )?;
+        }
+        if !self.caption.is_empty() {
+            writeln!(f, "{}", self.caption)?;
+        }
+        Ok(())
+    }
+}
+
+/// A day whose solving can be watched, one [`Frame`] per step.
+pub trait Visualise: Solution {
+    /// The steps of solving `input`, ending with the finished picture.
+    fn frames(input: Self::Input) -> Box<dyn Iterator<Item = Frame>>;
+}
+
+/// Every `n`th of `frames`, always keeping the last so that the finished
+/// picture is shown.
+pub fn every(frames: impl IntoIterator<Item = Frame>, n: usize) -> impl Iterator<Item = Frame> {
+    let n = n.max(1);
+    let mut frames = frames.into_iter().enumerate().peekable();
+    std::iter::from_fn(move || loop {
+        let (i, frame) = frames.next()?;
+        if i % n == 0 || frames.peek().is_none() {
+            return Some(frame);
+        }
+    })
+}
+
+/// Shows `frames` as an animation at `fps` frames a second, redrawing the
+/// screen for each one. Without a frame rate only the last frame is shown.
+pub fn play(
+    frames: impl IntoIterator<Item = Frame>,
+    fps: Option<f64>,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let Some(fps) = fps.filter(|&fps| fps > 0.0) else {
+        if let Some(last) = frames.into_iter().last() {
+            out.write_all(last.to_ansi().as_bytes())?;
+        }
+        return out.flush();
+    };
+
+    let delay = Duration::from_secs_f64(1.0 / fps);
+    for frame in frames {
+        write!(out, "{}{}", CLEAR_SCREEN, frame.to_ansi())?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let map: Grid<char> = "..#\n...\n".parse().unwrap();
+        Frame::new(&map, |&c| c).with_caption("Step 1")
+    }
+
+    #[test]
+    fn test_overlays() {
+        let mut frame = frame();
+        frame.overlay([Point::new(1, 0), Point::new(5, 5)], Some('@'), Colour::RED);
+        frame.highlight([Point::new(0, 2)], Colour::GREY);
+        assert_eq!(frame.to_string(), "..#\n@..\nStep 1\n");
+        assert_eq!(frame.cells[Point::new(0, 2)].bg, Some(Colour::GREY));
+    }
+
+    #[test]
+    fn test_ansi_styles_runs_of_cells_once() {
+        let mut frame = frame();
+        frame.overlay([Point::new(1, 1), Point::new(1, 2)], None, Colour::RED);
+        assert_eq!(
+            frame.to_ansi(),
+            "..#\n.\x1b[38;2;230;60;60m..\x1b[0m\nStep 1\n"
+        );
+    }
+
+    #[test]
+    fn test_every_keeps_the_last_frame() {
+        let frames = (1..=5).map(|i| frame().with_caption(format!("Step {}", i)));
+        let captions: Vec<String> = every(frames, 2).map(|frame| frame.caption).collect();
+        assert_eq!(captions, ["Step 1", "Step 3", "Step 5"]);
+
+        let frames = (1..=5).map(|i| frame().with_caption(format!("Step {}", i)));
+        assert_eq!(every(frames, 3).count(), 3);
+    }
+
+    #[test]
+    fn test_play_without_frame_rate_shows_last_frame() {
+        let frames = vec![frame(), frame().with_caption("Step 2")];
+        let mut out = Vec::new();
+        play(frames, None, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "..#\n...\nStep 2\n");
+    }
+}
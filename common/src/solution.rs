@@ -0,0 +1,113 @@
+use crate::error::ParseError;
+use crate::mode::prepare;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// A day's puzzle: parses the input once and answers both parts from it.
+pub trait Solution {
+    /// Day of the month this solution is for.
+    const DAY: u8;
+
+    /// Parsed form of the puzzle input shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Error returned by [`solve`].
+#[derive(Debug)]
+pub enum SolveError {
+    Parse(ParseError),
+    NoSuchPart { day: u8, part: u8 },
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::NoSuchPart { day, part } => write!(f, "Day {} has no part {}", day, part),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Parse(e) => Some(e),
+            SolveError::NoSuchPart { .. } => None,
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+/// Parses `input` in the current parse mode and solves the requested part,
+/// formatting the answer for display.
+pub fn solve<S: Solution>(part: u8, input: &str) -> Result<String, SolveError> {
+    if !(1..=2).contains(&part) {
+        return Err(SolveError::NoSuchPart { day: S::DAY, part });
+    }
+
+    let parsed = S::parse(&prepare(input)?)?;
+    match part {
+        1 => Ok(S::part1(&parsed).to_string()),
+        _ => Ok(S::part2(&parsed).to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::parse_numbers;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            parse_numbers(1, input)
+        }
+
+        fn part1(input: &Self::Input) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> usize {
+            input.len()
+        }
+    }
+
+    #[test]
+    fn test_solve_parts() {
+        assert_eq!(solve::<Sum>(1, "1 2 3").unwrap(), "6");
+        assert_eq!(solve::<Sum>(2, "1 2 3").unwrap(), "3");
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert!(matches!(
+            solve::<Sum>(1, "1 x"),
+            Err(SolveError::Parse(ParseError::InvalidNumber {
+                column: 3,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            solve::<Sum>(3, "1"),
+            Err(SolveError::NoSuchPart { day: 0, part: 3 })
+        ));
+    }
+}
@@ -1,62 +1,37 @@
-use std::fs::read_to_string;
-use std::io::Result;
-
-fn main() -> Result<()> {
-    // Read input from stdin
-    let input = read_to_string("./input.txt")?;
-
-    // Parse input into two vectors
-    let (left, right): (Vec<i32>, Vec<i32>) = parse_input(&input);
-
-    // Calculate total distance
-    let total_distance = calculate_distance(left, right);
-
-    println!("Total distance: {}", total_distance);
-
-    Ok(())
-}
-
-fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
-    let mut left = Vec::new();
-    let mut right = Vec::new();
-
-    for line in input.lines() {
-        if line.trim().is_empty() {
-            continue;
-        }
-
-        let numbers: Vec<&str> = line.split_whitespace().collect();
-        if numbers.len() == 2 {
-            if let (Ok(l), Ok(r)) = (numbers[0].parse(), numbers[1].parse()) {
-                left.push(l);
-                right.push(r);
-            }
-        }
+use common::output::{emit, run_batch, Args, HashingReader, Record};
+use day01::part1::{calculate_distance, parse_input};
+use day01::stream::{distance, DEFAULT_RUN_LEN};
+use std::error::Error;
+use std::io::BufReader;
+use std::time::Instant;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::from_env();
+    if let Some(arg) = args.batch() {
+        run_batch(1, 1, arg, args.format, |input| {
+            let (left, right) = parse_input(input).map_err(|e| e.to_string())?;
+            Ok(calculate_distance(left, right).to_string())
+        });
+        return Ok(());
     }
 
-    (left, right)
-}
+    // Sort the lists externally so that they needn't fit in memory
+    let mut reader = HashingReader::new(args.source(1).open()?);
 
-fn calculate_distance(mut left: Vec<i32>, mut right: Vec<i32>) -> i32 {
-    // Sort both lists
-    left.sort_unstable();
-    right.sort_unstable();
+    let start = Instant::now();
+    let total_distance = distance(BufReader::new(&mut reader), DEFAULT_RUN_LEN)?;
 
-    // Calculate total distance by pairing corresponding elements
-    left.iter()
-        .zip(right.iter())
-        .map(|(l, r)| (l - r).abs())
-        .sum()
-}
+    let record = Record {
+        day: 1,
+        part: 1,
+        answer: total_distance.to_string(),
+        elapsed: start.elapsed(),
+        input_hash: reader.hash(),
+        exact: true,
+    };
+    emit(args.format, &record, |answer| {
+        format!("Total distance: {}", answer)
+    });
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_example() {
-        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
-        let (left, right) = parse_input(input);
-        assert_eq!(calculate_distance(left, right), 11);
-    }
+    Ok(())
 }
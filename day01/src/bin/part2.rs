@@ -1,83 +1,44 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
-
-fn main() -> io::Result<()> {
-    let file = File::open("./input.txt")?;
-    let reader = BufReader::new(file);
-
-    let (left, right) = parse_input(reader)?;
-    let similarity_score = calculate_similarity_score(&left, &right);
-
-    println!("Similarity score: {}", similarity_score);
-
-    Ok(())
-}
-
-fn parse_input<R: BufRead>(reader: R) -> io::Result<(Vec<i32>, Vec<i32>)> {
-    let mut left = Vec::new();
-    let mut right = Vec::new();
-
-    for line in reader.lines() {
-        let line = line?;
-        if line.trim().is_empty() {
-            continue;
-        }
-
-        let numbers: Vec<&str> = line.split_whitespace().collect();
-        if numbers.len() == 2 {
-            if let (Ok(l), Ok(r)) = (numbers[0].parse(), numbers[1].parse()) {
-                left.push(l);
-                right.push(r);
-            }
-        }
+use common::output::{emit, run_batch, Args, HashingReader, Record};
+use day01::part2::{calculate_similarity_score, parse_input};
+use day01::stream::{similarity, SimilaritySketch};
+use std::error::Error;
+use std::io::BufReader;
+use std::time::Instant;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::from_env();
+    if let Some(arg) = args.batch() {
+        run_batch(1, 2, arg, args.format, |input| {
+            let (left, right) = parse_input(input.as_bytes()).map_err(|e| e.to_string())?;
+            Ok(calculate_similarity_score(&left, &right).to_string())
+        });
+        return Ok(());
     }
 
-    Ok((left, right))
-}
-
-fn calculate_similarity_score(left: &[i32], right: &[i32]) -> i64 {
-    // Create frequency map for right list
-    let right_freq: HashMap<i32, i32> = right.iter().fold(HashMap::new(), |mut map, &num| {
-        *map.entry(num).or_insert(0) += 1;
-        map
-    });
-
-    // Calculate similarity score
-    left.iter()
-        .map(|&num| {
-            let freq = right_freq.get(&num).copied().unwrap_or(0);
-            i64::from(num) * i64::from(freq)
-        })
-        .sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::io::Cursor;
+    // Stream the lists, counting IDs in bounded memory
+    let mut reader = HashingReader::new(args.source(1).open()?);
 
-    #[test]
-    fn test_example() {
-        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
-        let reader = BufReader::new(Cursor::new(input));
-        let (left, right) = parse_input(reader).unwrap();
-        assert_eq!(calculate_similarity_score(&left, &right), 31);
+    let start = Instant::now();
+    let similarity = similarity(BufReader::new(&mut reader), SimilaritySketch::default())?;
+    if !similarity.exact {
+        log::warn!("Too many distinct IDs to count exactly, the score is an estimate");
     }
 
-    #[test]
-    fn test_empty_input() {
-        let input = "";
-        let reader = BufReader::new(Cursor::new(input));
-        let (left, right) = parse_input(reader).unwrap();
-        assert_eq!(calculate_similarity_score(&left, &right), 0);
-    }
+    let record = Record {
+        day: 1,
+        part: 2,
+        answer: similarity.score.to_string(),
+        elapsed: start.elapsed(),
+        input_hash: reader.hash(),
+        exact: similarity.exact,
+    };
+    emit(args.format, &record, |answer| {
+        if record.exact {
+            format!("Similarity score: {}", answer)
+        } else {
+            format!("Similarity score: about {}", answer)
+        }
+    });
 
-    #[test]
-    fn test_no_matches() {
-        let input = "1   2\n3   4\n";
-        let reader = BufReader::new(Cursor::new(input));
-        let (left, right) = parse_input(reader).unwrap();
-        assert_eq!(calculate_similarity_score(&left, &right), 0);
-    }
+    Ok(())
 }
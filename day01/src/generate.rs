@@ -0,0 +1,18 @@
+use rand::Rng;
+use std::fmt::Write;
+
+/// `size` lines of location ID pairs. About half the right-hand IDs are
+/// copies of left-hand ones so the similarity score is not always zero.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let left: Vec<u32> = (0..size).map(|_| rng.gen_range(10_000..100_000)).collect();
+    let mut input = String::new();
+    for &id in &left {
+        let right = if rng.gen_bool(0.5) {
+            left[rng.gen_range(0..left.len())]
+        } else {
+            rng.gen_range(10_000..100_000)
+        };
+        writeln!(input, "{}   {}", id, right).unwrap();
+    }
+    input
+}
@@ -0,0 +1,47 @@
+use common::{ParseError, Solution};
+
+pub mod generate;
+pub mod lists;
+pub mod part1;
+pub mod part2;
+pub mod report;
+pub mod stream;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer1 = u64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part2::parse_input(input.as_bytes())
+    }
+
+    fn part1((left, right): &Self::Input) -> u64 {
+        part1::calculate_distance(left.clone(), right.clone())
+    }
+
+    fn part2((left, right): &Self::Input) -> i64 {
+        part2::calculate_similarity_score(left, right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_lines_are_reported() {
+        let error = Day01::parse("3   4\n4   x\n").unwrap_err();
+        assert_eq!(error.to_string(), "Line 2, column 5: invalid number \"x\"");
+
+        let error = Day01::parse("3   4\n4\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 1: expected two location IDs, found \"4\""
+        );
+    }
+}
@@ -0,0 +1,485 @@
+use common::error::parse_number;
+use common::mode::{is_strict, validate_line};
+use common::ParseError;
+use num_bigint::BigInt;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::hash::Hash;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// A location ID type the lists can be read as.
+pub trait Id: Ord + Hash + Clone + FromStr + Display {
+    /// The ID as an unbounded integer, for sums that would overflow.
+    fn to_bigint(&self) -> BigInt;
+
+    /// The ID as an `i128` if its type has a fixed width, so that metrics
+    /// can be summed without allocating until they overflow.
+    fn to_i128(&self) -> Option<i128>;
+}
+
+impl Id for i32 {
+    fn to_bigint(&self) -> BigInt {
+        BigInt::from(*self)
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        Some(i128::from(*self))
+    }
+}
+
+impl Id for i64 {
+    fn to_bigint(&self) -> BigInt {
+        BigInt::from(*self)
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        Some(i128::from(*self))
+    }
+}
+
+impl Id for BigInt {
+    fn to_bigint(&self) -> BigInt {
+        self.clone()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        None
+    }
+}
+
+/// How wide the IDs in the lists may be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdKind {
+    #[default]
+    I32,
+    I64,
+    /// Arbitrary precision.
+    Big,
+}
+
+impl FromStr for IdKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "i32" => Ok(IdKind::I32),
+            "i64" => Ok(IdKind::I64),
+            "big" => Ok(IdKind::Big),
+            _ => Err(format!("Unknown ID type {:?}, expected i32, i64 or big", s)),
+        }
+    }
+}
+
+impl Display for IdKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IdKind::I32 => write!(f, "i32"),
+            IdKind::I64 => write!(f, "i64"),
+            IdKind::Big => write!(f, "big"),
+        }
+    }
+}
+
+/// Which two of the whitespace separated columns hold the lists, counting
+/// from 0. Written as 1-based `LEFT,RIGHT`, e.g. `1,3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Columns {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl Default for Columns {
+    fn default() -> Columns {
+        Columns { left: 0, right: 1 }
+    }
+}
+
+impl FromStr for Columns {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let column = |n: &str| match n.trim().parse::<usize>() {
+            Ok(n) if n > 0 => Ok(n - 1),
+            _ => Err(format!("Invalid column {:?}, columns count from 1", n)),
+        };
+        let (left, right) = s
+            .split_once(',')
+            .ok_or_else(|| format!("Expected two columns as LEFT,RIGHT, found {:?}", s))?;
+        Ok(Columns {
+            left: column(left)?,
+            right: column(right)?,
+        })
+    }
+}
+
+impl Display for Columns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.left + 1, self.right + 1)
+    }
+}
+
+/// The two lists of location IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lists<T> {
+    pub left: Vec<T>,
+    pub right: Vec<T>,
+}
+
+/// Reads the lists from `columns` of `input`, see [`read_rows`].
+pub fn parse_lists<T: Id>(input: &str, columns: Columns) -> Result<Lists<T>, ParseError> {
+    let mut lists = Lists {
+        left: Vec::new(),
+        right: Vec::new(),
+    };
+    for row in read_rows(input.as_bytes(), columns) {
+        let (l, r) = row?;
+        lists.left.push(l);
+        lists.right.push(r);
+    }
+    Ok(lists)
+}
+
+/// The pair of IDs in `columns` of each line of `reader`, read a line at a
+/// time. Every line must have as many columns as the first; only the
+/// selected ones need to be IDs. Blank lines are skipped unless parsing is
+/// strict, when lines are also checked as [`common::mode::prepare`] would
+/// check the whole input.
+pub fn read_rows<T: Id, R: BufRead>(
+    reader: R,
+    columns: Columns,
+) -> impl Iterator<Item = Result<(T, T), ParseError>> {
+    let strict = is_strict();
+    let mut width = None;
+    // Split on '\n' only, so that a '\r' before it is still seen when strict
+    reader
+        .split(b'\n')
+        .enumerate()
+        .filter_map(move |(i, line)| {
+            let line = line.and_then(|bytes| {
+                String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            });
+            let row = line.map_err(ParseError::from).and_then(|line| {
+                if strict {
+                    validate_line(i + 1, &line)?;
+                }
+                parse_row(i + 1, &line, columns, strict, &mut width)
+            });
+            row.transpose()
+        })
+}
+
+/// Parses one line, or `None` for a blank line when lenient. `width` is the
+/// number of columns of the first line, once there has been one.
+fn parse_row<T: Id>(
+    line_number: usize,
+    line: &str,
+    columns: Columns,
+    strict: bool,
+    width: &mut Option<usize>,
+) -> Result<Option<(T, T)>, ParseError> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if tokens.is_empty() && !strict {
+        return Ok(None);
+    }
+
+    let (Some(l), Some(r)) = (tokens.get(columns.left), tokens.get(columns.right)) else {
+        return Err(ParseError::Malformed {
+            line: line_number,
+            column: 1,
+            text: line.to_string(),
+            expected: "two location IDs",
+        });
+    };
+    let expected = *width.get_or_insert(tokens.len());
+    if tokens.len() != expected {
+        return Err(ParseError::RaggedRow {
+            line: line_number,
+            width: tokens.len(),
+            expected,
+        });
+    }
+    Ok(Some((
+        parse_number(line_number, line, l)?,
+        parse_number(line_number, line, r)?,
+    )))
+}
+
+/// A way of comparing the two lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Metric {
+    /// Sum of the distances between the lists' IDs paired in sorted order.
+    #[default]
+    Distance,
+    /// Sum of the squares of those distances.
+    SquaredDistance,
+    /// Sum of each left ID times how often it appears on the right.
+    Similarity,
+    /// Distinct IDs in both lists as a fraction of those in either.
+    Jaccard,
+    /// Each ID that appears a different number of times in each list.
+    Diff,
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "distance" => Ok(Metric::Distance),
+            "squared" => Ok(Metric::SquaredDistance),
+            "similarity" => Ok(Metric::Similarity),
+            "jaccard" => Ok(Metric::Jaccard),
+            "diff" => Ok(Metric::Diff),
+            _ => Err(format!(
+                "Unknown metric {:?}, expected distance, squared, similarity, jaccard or diff",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Metric::Distance => write!(f, "distance"),
+            Metric::SquaredDistance => write!(f, "squared"),
+            Metric::Similarity => write!(f, "similarity"),
+            Metric::Jaccard => write!(f, "jaccard"),
+            Metric::Diff => write!(f, "diff"),
+        }
+    }
+}
+
+impl Metric {
+    /// The metric of `lists`, formatted for printing.
+    pub fn measure<T: Id>(self, lists: &Lists<T>) -> String {
+        match self {
+            Metric::Distance => distance(lists).to_string(),
+            Metric::SquaredDistance => squared_distance(lists).to_string(),
+            Metric::Similarity => similarity(lists).to_string(),
+            Metric::Jaccard => format!("{:.6}", jaccard(lists)),
+            Metric::Diff => diff(lists)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+/// Parses `input` with IDs of the given kind and measures it.
+pub fn measure(
+    input: &str,
+    columns: Columns,
+    ids: IdKind,
+    metric: Metric,
+) -> Result<String, ParseError> {
+    Ok(match ids {
+        IdKind::I32 => metric.measure(&parse_lists::<i32>(input, columns)?),
+        IdKind::I64 => metric.measure(&parse_lists::<i64>(input, columns)?),
+        IdKind::Big => metric.measure(&parse_lists::<BigInt>(input, columns)?),
+    })
+}
+
+/// The IDs of the lists paired in sorted order.
+fn sorted_pairs<T: Id>(lists: &Lists<T>) -> Vec<(T, T)> {
+    let mut left = lists.left.clone();
+    let mut right = lists.right.clone();
+    left.sort_unstable();
+    right.sort_unstable();
+    left.into_iter().zip(right).collect()
+}
+
+/// The sum of a term of each pair, in `u128` when `narrow` gives every
+/// term and the sum fits, otherwise of the `wide` terms.
+fn sum_terms<T>(
+    pairs: &[(T, T)],
+    narrow: impl Fn(&(T, T)) -> Option<u128>,
+    wide: impl Fn(&(T, T)) -> BigInt,
+) -> BigInt {
+    pairs
+        .iter()
+        .try_fold(0u128, |sum, pair| sum.checked_add(narrow(pair)?))
+        .map(BigInt::from)
+        .unwrap_or_else(|| pairs.iter().map(wide).sum())
+}
+
+/// The distance between two IDs.
+pub fn id_distance<T: Id>(l: &T, r: &T) -> BigInt {
+    match (l.to_i128(), r.to_i128()) {
+        (Some(l), Some(r)) => BigInt::from(l.abs_diff(r)),
+        _ => BigInt::from((l.to_bigint() - r.to_bigint()).magnitude().clone()),
+    }
+}
+
+pub fn distance<T: Id>(lists: &Lists<T>) -> BigInt {
+    let pairs = sorted_pairs(lists);
+    sum_terms(
+        &pairs,
+        |(l, r)| Some(l.to_i128()?.abs_diff(r.to_i128()?)),
+        |(l, r)| id_distance(l, r),
+    )
+}
+
+pub fn squared_distance<T: Id>(lists: &Lists<T>) -> BigInt {
+    let pairs = sorted_pairs(lists);
+    sum_terms(
+        &pairs,
+        |(l, r)| {
+            let d = l.to_i128()?.abs_diff(r.to_i128()?);
+            d.checked_mul(d)
+        },
+        |(l, r)| {
+            let d = l.to_bigint() - r.to_bigint();
+            &d * &d
+        },
+    )
+}
+
+pub fn similarity<T: Id>(lists: &Lists<T>) -> BigInt {
+    let mut counts: HashMap<&T, u64> = HashMap::new();
+    for id in &lists.right {
+        *counts.entry(id).or_default() += 1;
+    }
+    let count = |id: &T| counts.get(id).copied().unwrap_or(0);
+    let narrow = lists.left.iter().try_fold(0i128, |sum, id| {
+        sum.checked_add(id.to_i128()?.checked_mul(i128::from(count(id)))?)
+    });
+    narrow
+        .map(BigInt::from)
+        .unwrap_or_else(|| lists.left.iter().map(|id| id.to_bigint() * count(id)).sum())
+}
+
+/// The Jaccard index of the lists' distinct IDs; 1 when both are empty.
+pub fn jaccard<T: Id>(lists: &Lists<T>) -> f64 {
+    let left: HashSet<&T> = lists.left.iter().collect();
+    let right: HashSet<&T> = lists.right.iter().collect();
+    let union = left.union(&right).count();
+    if union == 0 {
+        return 1.0;
+    }
+    left.intersection(&right).count() as f64 / union as f64
+}
+
+/// How often an ID appears in each list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdDiff<T> {
+    pub id: T,
+    pub left: usize,
+    pub right: usize,
+}
+
+impl<T: Display> Display for IdDiff<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: left {}, right {}", self.id, self.left, self.right)
+    }
+}
+
+/// The IDs appearing a different number of times in each list, in order.
+pub fn diff<T: Id>(lists: &Lists<T>) -> Vec<IdDiff<T>> {
+    let mut counts: BTreeMap<&T, (usize, usize)> = BTreeMap::new();
+    for id in &lists.left {
+        counts.entry(id).or_default().0 += 1;
+    }
+    for id in &lists.right {
+        counts.entry(id).or_default().1 += 1;
+    }
+    counts
+        .into_iter()
+        .filter(|(_, (left, right))| left != right)
+        .map(|(id, (left, right))| IdDiff {
+            id: id.clone(),
+            left,
+            right,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::mode::{with_parse_mode, ParseMode};
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_metrics_of_example() {
+        let lists: Lists<i32> = parse_lists(EXAMPLE, Columns::default()).unwrap();
+        assert_eq!(distance(&lists), BigInt::from(11));
+        assert_eq!(squared_distance(&lists), BigInt::from(35));
+        assert_eq!(similarity(&lists), BigInt::from(31));
+        // {1, 2, 3, 4} and {3, 4, 5, 9}
+        assert_eq!(jaccard(&lists), 2.0 / 6.0);
+        assert_eq!(
+            Metric::Diff.measure(&lists),
+            "1: left 1, right 0\n2: left 1, right 0\n5: left 0, right 1\n9: left 0, right 1"
+        );
+    }
+
+    #[test]
+    fn test_selected_columns() {
+        let input = "a 3 x 4\nb 4 y 3\n";
+        let columns: Columns = "4,2".parse().unwrap();
+        let lists: Lists<i64> = parse_lists(input, columns).unwrap();
+        assert_eq!(lists.left, [4, 3]);
+        assert_eq!(lists.right, [3, 4]);
+        assert_eq!(columns.to_string(), "4,2");
+        assert!("0,1".parse::<Columns>().is_err());
+
+        let error = parse_lists::<i32>("1 2 3\n1 2\n", columns).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 1, column 1: expected two location IDs, found \"1 2 3\""
+        );
+        let error = parse_lists::<i32>("1 2 3\n1 2\n", Columns::default()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2: row has 2 cells but the first row has 3"
+        );
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let lists: Lists<i32> = parse_lists("3   4\n  \n4   3\n", Columns::default()).unwrap();
+        assert_eq!(lists.left, [3, 4]);
+        let strict = with_parse_mode(ParseMode::Strict, || {
+            parse_lists::<i32>("3   4\n\n4   3\n", Columns::default())
+        });
+        assert_eq!(
+            strict.unwrap_err().to_string(),
+            "Line 2, column 1: expected two location IDs, found \"\""
+        );
+    }
+
+    #[test]
+    fn test_wide_ids() {
+        let input = "9223372036854775807 -9223372036854775808\n";
+        assert!(measure(input, Columns::default(), IdKind::I32, Metric::Distance).is_err());
+        assert_eq!(
+            measure(input, Columns::default(), IdKind::I64, Metric::Distance).unwrap(),
+            "18446744073709551615"
+        );
+
+        // Two squares this size overflow a u128
+        let input = "9223372036854775807 -9223372036854775808\n".repeat(2);
+        for ids in [IdKind::I64, IdKind::Big] {
+            assert_eq!(
+                measure(&input, Columns::default(), ids, Metric::SquaredDistance).unwrap(),
+                "680564733841876926852962238568698216450"
+            );
+        }
+
+        let input = "100000000000000000000000 1\n";
+        assert_eq!(
+            measure(
+                input,
+                Columns::default(),
+                IdKind::Big,
+                Metric::SquaredDistance
+            )
+            .unwrap(),
+            "9999999999999999999999800000000000000000000001"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::lists::{parse_lists, Columns};
+use common::ParseError;
+
+pub fn solve(input: &str) -> u64 {
+    let (left, right) = parse_input(input).expect("Failed to parse input");
+    calculate_distance(left, right)
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let lists = parse_lists(input, Columns::default())?;
+    Ok((lists.left, lists.right))
+}
+
+pub fn calculate_distance(mut left: Vec<i32>, mut right: Vec<i32>) -> u64 {
+    // Sort both lists
+    left.sort_unstable();
+    right.sort_unstable();
+
+    // Calculate total distance by pairing corresponding elements
+    left.iter()
+        .zip(right.iter())
+        .map(|(l, r)| u64::from(l.abs_diff(*r)))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let (left, right) = parse_input(input).unwrap();
+        assert_eq!(calculate_distance(left, right), 11);
+    }
+
+    #[test]
+    fn test_extreme_ids() {
+        let distance = calculate_distance(vec![i32::MIN, i32::MAX], vec![i32::MAX, i32::MAX]);
+        assert_eq!(distance, u64::from(u32::MAX));
+    }
+}
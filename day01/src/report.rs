@@ -0,0 +1,269 @@
+use crate::lists::{id_distance, parse_lists, Columns, Id, IdKind, Lists};
+use common::batch::Table;
+use common::ParseError;
+use num_bigint::BigInt;
+use std::collections::HashSet;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+/// How a [`Reconciliation`] is written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    /// Aligned tables for reading.
+    #[default]
+    Text,
+    /// One row per line, tagged with the section it belongs to.
+    Csv,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(ReportFormat::Text),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => Err(format!(
+                "Unknown report format {:?}, expected text or csv",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReportFormat::Text => write!(f, "text"),
+            ReportFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// IDs paired by their position in the sorted lists, ranked from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair<T> {
+    pub rank: usize,
+    pub left: T,
+    pub right: T,
+    pub distance: BigInt,
+}
+
+/// Where the two lists disagree: every sorted pair with its distance, the
+/// pairs contributing most to the total, and IDs found in only one list.
+/// Only as many pairs as the shorter list has are made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reconciliation<T> {
+    pub pairs: Vec<Pair<T>>,
+    pub total: BigInt,
+    /// Indices into `pairs` of the largest non-zero distances, largest first.
+    pub top: Vec<usize>,
+    /// Distinct IDs of the left list missing from the right, in order.
+    pub left_only: Vec<T>,
+    pub right_only: Vec<T>,
+}
+
+pub fn reconcile<T: Id>(lists: &Lists<T>, top: usize) -> Reconciliation<T> {
+    let mut left = lists.left.clone();
+    let mut right = lists.right.clone();
+    left.sort_unstable();
+    right.sort_unstable();
+
+    let pairs: Vec<Pair<T>> = left
+        .iter()
+        .zip(&right)
+        .enumerate()
+        .map(|(i, (l, r))| Pair {
+            rank: i + 1,
+            left: l.clone(),
+            right: r.clone(),
+            distance: id_distance(l, r),
+        })
+        .collect();
+    let total = pairs.iter().map(|pair| &pair.distance).sum();
+
+    let mut largest: Vec<usize> = (0..pairs.len())
+        .filter(|&i| pairs[i].distance > BigInt::ZERO)
+        .collect();
+    // Stable, so ties keep their rank order
+    largest.sort_by(|&a, &b| pairs[b].distance.cmp(&pairs[a].distance));
+    largest.truncate(top);
+
+    Reconciliation {
+        total,
+        top: largest,
+        left_only: only_in(&left, &right),
+        right_only: only_in(&right, &left),
+        pairs,
+    }
+}
+
+/// The distinct IDs of sorted `ids` that are not in `others`.
+fn only_in<T: Id>(ids: &[T], others: &[T]) -> Vec<T> {
+    let others: HashSet<&T> = others.iter().collect();
+    let mut only: Vec<T> = ids
+        .iter()
+        .filter(|id| !others.contains(id))
+        .cloned()
+        .collect();
+    only.dedup();
+    only
+}
+
+impl<T: Id> Reconciliation<T> {
+    pub fn write(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Text => self.to_text(),
+            ReportFormat::Csv => self.to_csv(),
+        }
+    }
+
+    fn pair_table<'a>(pairs: impl Iterator<Item = &'a Pair<T>>) -> Table
+    where
+        T: 'a,
+    {
+        let mut table = Table::new(&["Rank", "Left", "Right", "Distance"]);
+        for pair in pairs {
+            table.push(vec![
+                pair.rank.to_string(),
+                pair.left.to_string(),
+                pair.right.to_string(),
+                pair.distance.to_string(),
+            ]);
+        }
+        table
+    }
+
+    fn to_text(&self) -> String {
+        let ids = |ids: &[T]| match ids {
+            [] => "none".to_string(),
+            ids => ids
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+
+        let mut text = String::new();
+        write!(text, "{}", Self::pair_table(self.pairs.iter())).unwrap();
+        writeln!(text, "Total distance: {}", self.total).unwrap();
+        writeln!(text).unwrap();
+        writeln!(text, "Top {} contributors:", self.top.len()).unwrap();
+        write!(
+            text,
+            "{}",
+            Self::pair_table(self.top.iter().map(|&i| &self.pairs[i]))
+        )
+        .unwrap();
+        writeln!(text).unwrap();
+        writeln!(text, "Left only: {}", ids(&self.left_only)).unwrap();
+        writeln!(text, "Right only: {}", ids(&self.right_only)).unwrap();
+        text
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("section,rank,left,right,distance\n");
+        let pair = |csv: &mut String, section: &str, pair: &Pair<T>| {
+            writeln!(
+                csv,
+                "{},{},{},{},{}",
+                section, pair.rank, pair.left, pair.right, pair.distance
+            )
+            .unwrap();
+        };
+        for p in &self.pairs {
+            pair(&mut csv, "pair", p);
+        }
+        for &i in &self.top {
+            pair(&mut csv, "top", &self.pairs[i]);
+        }
+        for id in &self.left_only {
+            writeln!(csv, "left_only,,{},,", id).unwrap();
+        }
+        for id in &self.right_only {
+            writeln!(csv, "right_only,,,{},", id).unwrap();
+        }
+        csv
+    }
+}
+
+/// Parses `input` with IDs of the given kind and writes its reconciliation.
+pub fn report(
+    input: &str,
+    columns: Columns,
+    ids: IdKind,
+    top: usize,
+    format: ReportFormat,
+) -> Result<String, ParseError> {
+    Ok(match ids {
+        IdKind::I32 => reconcile(&parse_lists::<i32>(input, columns)?, top).write(format),
+        IdKind::I64 => reconcile(&parse_lists::<i64>(input, columns)?, top).write(format),
+        IdKind::Big => reconcile(&parse_lists::<BigInt>(input, columns)?, top).write(format),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_reconcile_example() {
+        let lists: Lists<i32> = parse_lists(EXAMPLE, Columns::default()).unwrap();
+        let reconciliation = reconcile(&lists, 3);
+        assert_eq!(reconciliation.total, BigInt::from(11));
+        assert_eq!(reconciliation.pairs.len(), 6);
+        // Distances are 2, 1, 0, 1, 2, 5 by rank
+        assert_eq!(reconciliation.top, [5, 0, 4]);
+        assert_eq!(reconciliation.left_only, [1, 2]);
+        assert_eq!(reconciliation.right_only, [5, 9]);
+    }
+
+    #[test]
+    fn test_text_report() {
+        let text = report(
+            "1 2\n5 5\n",
+            Columns::default(),
+            IdKind::I32,
+            5,
+            ReportFormat::Text,
+        )
+        .unwrap();
+        assert_eq!(
+            text,
+            "Rank  Left  Right  Distance\n\
+             1     1     2      1\n\
+             2     5     5      0\n\
+             Total distance: 1\n\
+             \n\
+             Top 1 contributors:\n\
+             Rank  Left  Right  Distance\n\
+             1     1     2      1\n\
+             \n\
+             Left only: 1\n\
+             Right only: 2\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_report() {
+        let csv = report(
+            "1 2\n5 5\n",
+            Columns::default(),
+            IdKind::I64,
+            1,
+            ReportFormat::Csv,
+        )
+        .unwrap();
+        assert_eq!(
+            csv,
+            "section,rank,left,right,distance\n\
+             pair,1,1,2,1\n\
+             pair,2,5,5,0\n\
+             top,1,1,2,1\n\
+             left_only,,1,,\n\
+             right_only,,,2,\n"
+        );
+    }
+}
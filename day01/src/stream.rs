@@ -0,0 +1,391 @@
+use crate::lists::{read_rows, Columns};
+use common::ParseError;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// IDs of each list held in memory before a sorted run of them is written
+/// to a temporary file, 64MiB a list.
+pub const DEFAULT_RUN_LEN: usize = 1 << 24;
+
+/// The total distance between the lists in `reader`, which may be too large
+/// to hold in memory. Each list is sorted in runs of `run_len` IDs that are
+/// spilled to temporary files and then merged.
+pub fn distance<R: BufRead>(reader: R, run_len: usize) -> Result<u64, ParseError> {
+    let mut left = Column::new(run_len);
+    let mut right = Column::new(run_len);
+    for pair in read_rows(reader, Columns::default()) {
+        let (l, r) = pair?;
+        left.push(l)?;
+        right.push(r)?;
+    }
+
+    let mut total = 0;
+    for (l, r) in left.sorted()?.zip(right.sorted()?) {
+        total += u64::from(l?.abs_diff(r?));
+    }
+    Ok(total)
+}
+
+/// One list of IDs being sorted externally.
+struct Column {
+    run_len: usize,
+    buffer: Vec<i32>,
+    runs: Vec<Run>,
+}
+
+impl Column {
+    fn new(run_len: usize) -> Column {
+        Column {
+            run_len: run_len.max(1),
+            buffer: Vec::new(),
+            runs: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, id: i32) -> io::Result<()> {
+        self.buffer.push(id);
+        if self.buffer.len() >= self.run_len {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        self.runs.push(Run::write(&self.buffer)?);
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// The IDs in ascending order. A list that fitted in one run is sorted
+    /// in memory without touching the disk.
+    fn sorted(mut self) -> io::Result<Box<dyn Iterator<Item = io::Result<i32>>>> {
+        if self.runs.is_empty() {
+            self.buffer.sort_unstable();
+            return Ok(Box::new(self.buffer.into_iter().map(Ok)));
+        }
+        if !self.buffer.is_empty() {
+            self.spill()?;
+        }
+        Ok(Box::new(Merge::new(self.runs)?))
+    }
+}
+
+/// A sorted run of IDs in a temporary file, removed when dropped.
+struct Run {
+    path: PathBuf,
+}
+
+impl Run {
+    fn write(ids: &[i32]) -> io::Result<Run> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        // The names are easy to guess, so a file already there, which may be
+        // a link planted elsewhere, is never opened; the next name is tried
+        let (run, file) = loop {
+            let path = env::temp_dir().join(format!(
+                "day01-run-{}-{}",
+                process::id(),
+                NEXT.fetch_add(1, Ordering::Relaxed)
+            ));
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (Run { path }, file),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        };
+        // From here on, returning early drops `run` and removes the file
+        let mut writer = BufWriter::new(file);
+        for id in ids {
+            writer.write_all(&id.to_le_bytes())?;
+        }
+        writer.flush()?;
+        Ok(run)
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// The IDs of several sorted runs, merged into one ascending sequence.
+struct Merge {
+    readers: Vec<BufReader<File>>,
+    heap: BinaryHeap<Reverse<(i32, usize)>>,
+    _runs: Vec<Run>,
+}
+
+impl Merge {
+    fn new(runs: Vec<Run>) -> io::Result<Merge> {
+        let mut merge = Merge {
+            readers: Vec::with_capacity(runs.len()),
+            heap: BinaryHeap::with_capacity(runs.len()),
+            _runs: Vec::new(),
+        };
+        for (i, run) in runs.iter().enumerate() {
+            merge.readers.push(BufReader::new(File::open(&run.path)?));
+            merge.refill(i)?;
+        }
+        merge._runs = runs;
+        Ok(merge)
+    }
+
+    /// Queues the next ID of run `i`, if it has one.
+    fn refill(&mut self, i: usize) -> io::Result<()> {
+        let mut bytes = [0; 4];
+        match self.readers[i].read_exact(&mut bytes) {
+            Ok(()) => self.heap.push(Reverse((i32::from_le_bytes(bytes), i))),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {}
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((id, i)) = self.heap.pop()?;
+        Some(self.refill(i).map(|()| id))
+    }
+}
+
+/// The similarity score of the lists in `reader`, counting IDs with `sketch`
+/// so that memory stays bounded however many distinct IDs there are.
+pub fn similarity<R: BufRead>(
+    reader: R,
+    mut sketch: SimilaritySketch,
+) -> Result<Similarity, ParseError> {
+    for pair in read_rows(reader, Columns::default()) {
+        let (l, r) = pair?;
+        sketch.add_left(l);
+        sketch.add_right(r);
+    }
+    Ok(sketch.similarity())
+}
+
+/// A similarity score and whether it was counted exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Similarity {
+    pub score: i128,
+    /// False when there were too many distinct IDs to count each one, so
+    /// `score` is the sketch's estimate.
+    pub exact: bool,
+}
+
+/// Seeds of the hash for each row of a sketch, which bounds its depth.
+const SEEDS: [u64; 8] = [
+    0x243f_6a88_85a3_08d3,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+    0x4528_21e6_38d0_1377,
+    0xbe54_66cf_34e9_0c6c,
+    0xc0ac_29b7_c97c_50dd,
+    0x3f84_d5b5_b547_0917,
+];
+
+/// Counts of each ID in the two lists, exact until there are more than
+/// `capacity` distinct IDs and then a count-min sketch of fixed size.
+///
+/// Each row of the sketch hashes an ID to a bucket that sums the IDs seen
+/// in the left list and counts those in the right. IDs sharing a bucket
+/// only add to a row's score, so for non-negative IDs the smallest row's
+/// score is an overestimate that tightens as the rows widen.
+#[derive(Debug, Clone)]
+pub struct SimilaritySketch {
+    capacity: usize,
+    width: usize,
+    depth: usize,
+    exact: HashMap<i32, (u64, u64)>,
+    rows: Vec<Vec<Bucket>>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Bucket {
+    left: i128,
+    right: u64,
+}
+
+impl Default for SimilaritySketch {
+    /// Exact up to a million distinct IDs, then four rows of 2^18 buckets.
+    fn default() -> SimilaritySketch {
+        SimilaritySketch::new(1 << 20, 1 << 18, 4)
+    }
+}
+
+impl SimilaritySketch {
+    /// A sketch counting up to `capacity` distinct IDs exactly, then `depth`
+    /// rows of `width` buckets. The depth is at most 8.
+    pub fn new(capacity: usize, width: usize, depth: usize) -> SimilaritySketch {
+        SimilaritySketch {
+            capacity,
+            width: width.max(1),
+            depth: depth.clamp(1, SEEDS.len()),
+            exact: HashMap::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_left(&mut self, id: i32) {
+        self.add(id, 1, 0);
+    }
+
+    pub fn add_right(&mut self, id: i32) {
+        self.add(id, 0, 1);
+    }
+
+    fn add(&mut self, id: i32, left: u64, right: u64) {
+        if self.rows.is_empty() {
+            let counts = self.exact.entry(id).or_default();
+            counts.0 += left;
+            counts.1 += right;
+            if self.exact.len() > self.capacity {
+                self.rows = vec![vec![Bucket::default(); self.width]; self.depth];
+                for (id, (left, right)) in std::mem::take(&mut self.exact) {
+                    self.add_to_rows(id, left, right);
+                }
+            }
+        } else {
+            self.add_to_rows(id, left, right);
+        }
+    }
+
+    fn add_to_rows(&mut self, id: i32, left: u64, right: u64) {
+        for row in 0..self.depth {
+            let i = self.bucket(row, id);
+            let bucket = &mut self.rows[row][i];
+            bucket.left += i128::from(id) * i128::from(left);
+            bucket.right += right;
+        }
+    }
+
+    fn bucket(&self, row: usize, id: i32) -> usize {
+        let hash = (u64::from(id as u32) ^ SEEDS[row]).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        ((hash >> 32) % self.width as u64) as usize
+    }
+
+    pub fn similarity(&self) -> Similarity {
+        if self.rows.is_empty() {
+            let score = self
+                .exact
+                .iter()
+                .map(|(&id, &(left, right))| i128::from(id) * i128::from(left) * i128::from(right))
+                .sum();
+            return Similarity { score, exact: true };
+        }
+
+        let score = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|bucket| bucket.left * i128::from(bucket.right))
+                    .sum::<i128>()
+            })
+            .min()
+            .unwrap_or(0);
+        Similarity {
+            score,
+            exact: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, part1, part2};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_distance_spills_runs() {
+        assert_eq!(distance(EXAMPLE.as_bytes(), DEFAULT_RUN_LEN).unwrap(), 11);
+        assert_eq!(distance(EXAMPLE.as_bytes(), 2).unwrap(), 11);
+
+        let input = generate::input(&mut StdRng::seed_from_u64(1), 1000);
+        let (left, right) = part2::parse_input(input.as_bytes()).unwrap();
+        assert_eq!(
+            distance(input.as_bytes(), 7).unwrap(),
+            part1::calculate_distance(left, right)
+        );
+    }
+
+    #[test]
+    fn test_runs_are_removed() {
+        let run = Run::write(&[1, 2, 3]).unwrap();
+        let path = run.path.clone();
+        assert_eq!(fs::metadata(&path).unwrap().len(), 12);
+        drop(run);
+        assert!(!path.exists());
+
+        let mut column = Column::new(2);
+        for id in [4, 3, 2, 1, 0] {
+            column.push(id).unwrap();
+        }
+        let paths: Vec<PathBuf> = column.runs.iter().map(|run| run.path.clone()).collect();
+        assert_eq!(paths.len(), 2);
+        // As when a later line fails to parse
+        drop(column);
+        assert!(paths.iter().all(|path| !path.exists()));
+    }
+
+    #[test]
+    fn test_similarity_is_exact_within_capacity() {
+        let similarity = similarity(EXAMPLE.as_bytes(), SimilaritySketch::default()).unwrap();
+        assert_eq!(
+            similarity,
+            Similarity {
+                score: 31,
+                exact: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_sketch_overestimates() {
+        let wide = similarity(EXAMPLE.as_bytes(), SimilaritySketch::new(2, 1 << 12, 4)).unwrap();
+        assert!(!wide.exact);
+        assert_eq!(wide.score, 31);
+
+        let narrow = similarity(EXAMPLE.as_bytes(), SimilaritySketch::new(2, 2, 1)).unwrap();
+        assert!(narrow.score >= 31);
+    }
+
+    #[test]
+    fn test_parse_errors_are_reported() {
+        assert!(distance("3   4\n4\n".as_bytes(), 1).is_err());
+        assert!(similarity("3   x\n".as_bytes(), SimilaritySketch::default()).is_err());
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_untidy_lines() {
+        use common::mode::{with_parse_mode, ParseMode};
+
+        for input in ["3   4\r\n4   3\r\n", "3   4 \n4   3\n"] {
+            let lenient = with_parse_mode(ParseMode::Lenient, || distance(input.as_bytes(), 1));
+            assert_eq!(lenient.unwrap(), 0);
+            let strict = with_parse_mode(ParseMode::Strict, || {
+                similarity(input.as_bytes(), SimilaritySketch::default())
+            });
+            assert_eq!(
+                strict.unwrap_err().to_string(),
+                format!(
+                    "Line 1, column 6: unexpected character {:?}",
+                    input.chars().nth(5).unwrap()
+                )
+            );
+        }
+    }
+}
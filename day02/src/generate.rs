@@ -0,0 +1,23 @@
+use rand::Rng;
+use std::fmt::Write;
+
+/// `size` reports of five to eight levels. Most are safe or one level away
+/// from safe, so both parts have something to count.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let sign = if rng.gen_bool(0.5) { 1 } else { -1 };
+        let mut levels = vec![rng.gen_range(1..100)];
+        for _ in 0..rng.gen_range(4..8) {
+            let step = rng.gen_range(1..=3);
+            levels.push(levels.last().unwrap() + sign * step);
+        }
+        if rng.gen_bool(0.5) {
+            let i = rng.gen_range(0..levels.len());
+            levels[i] = rng.gen_range(1..100);
+        }
+        let levels: Vec<String> = levels.iter().map(|level: &i32| level.to_string()).collect();
+        writeln!(input, "{}", levels.join(" ")).unwrap();
+    }
+    input
+}
@@ -0,0 +1,61 @@
+use common::error::parse_numbers;
+use common::mode::content_lines;
+use common::{ParseError, Solution};
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod rules;
+
+/// The change in level from `from` to `to`. Two `i32` levels can be further
+/// apart than an `i32` holds, so the step is an `i64`.
+pub fn step(from: i32, to: i32) -> i64 {
+    i64::from(to) - i64::from(from)
+}
+
+/// Parses the levels of each non-empty report line.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    content_lines(input)
+        .map(|line| line.and_then(|(number, line)| parse_numbers(number, line)))
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    /// Levels of each non-empty report line
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(reports: &Self::Input) -> usize {
+        reports
+            .iter()
+            .filter(|levels| part1::is_safe_levels(levels))
+            .count()
+    }
+
+    fn part2(reports: &Self::Input) -> usize {
+        reports
+            .iter()
+            .filter(|levels| part2::is_safe_levels_with_dampener(levels))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extreme_steps() {
+        assert_eq!(step(i32::MIN, i32::MAX), i64::from(u32::MAX));
+        assert_eq!(step(i32::MAX, i32::MIN), -i64::from(u32::MAX));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{parse_input, step};
+use common::error::parse_numbers;
+use common::ParseError;
+
+pub fn count_safe_reports(input: &str) -> usize {
+    parse_input(input)
+        .expect("Failed to parse input")
+        .iter()
+        .filter(|levels| is_safe_levels(levels))
+        .count()
+}
+
+pub fn is_safe_report(line: &str) -> Result<bool, ParseError> {
+    Ok(is_safe_levels(&parse_numbers(1, line)?))
+}
+
+/// Whether `numbers` is safe. A single level has no steps that could be
+/// unsafe, so it is safe as part 2 has it; a report with no levels is not.
+pub fn is_safe_levels(numbers: &[i32]) -> bool {
+    if numbers.is_empty() {
+        return false;
+    }
+
+    // Check if sequence is strictly increasing or decreasing
+    let mut increasing = true;
+    let mut decreasing = true;
+
+    for i in 1..numbers.len() {
+        let diff = step(numbers[i - 1], numbers[i]);
+
+        // Check if difference is between 1 and 3 (inclusive)
+        if diff.abs() < 1 || diff.abs() > 3 {
+            return false;
+        }
+
+        if diff > 0 {
+            decreasing = false;
+        } else {
+            increasing = false;
+        }
+
+        // If neither increasing nor decreasing, sequence is invalid
+        if !increasing && !decreasing {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_cases() {
+        let test_cases = [
+            ("7 6 4 2 1", true),  // Safe - decreasing
+            ("1 2 7 8 9", false), // Unsafe - jump too large
+            ("9 7 6 2 1", false), // Unsafe - jump too large
+            ("1 3 2 4 5", false), // Unsafe - not monotonic
+            ("8 6 4 4 1", false), // Unsafe - no change
+            ("1 3 6 7 9", true),  // Safe - increasing
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                is_safe_report(input).unwrap(),
+                expected,
+                "Failed for input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_short_reports() {
+        assert!(is_safe_report("5").unwrap());
+        assert!(!is_safe_report("").unwrap());
+    }
+
+    #[test]
+    fn test_bad_levels_are_reported() {
+        let error = is_safe_report("7 6 x 2 1").unwrap_err();
+        assert_eq!(error.to_string(), "Line 1, column 5: invalid number \"x\"");
+    }
+
+    #[test]
+    fn test_full_example() {
+        let input = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+        assert_eq!(count_safe_reports(input), 2);
+    }
+}
@@ -0,0 +1,318 @@
+use crate::rules::Rules;
+use crate::{parse_input, step};
+use common::error::parse_numbers;
+use common::ParseError;
+use std::fmt;
+
+pub fn count_safe_reports_with_dampener(input: &str) -> usize {
+    parse_input(input)
+        .expect("Failed to parse input")
+        .iter()
+        .filter(|levels| is_safe_levels_with_dampener(levels))
+        .count()
+}
+
+/// Whether `numbers` is safe as it is. A single level has no steps that
+/// could be unsafe.
+pub fn is_safe_sequence(numbers: &[i32]) -> bool {
+    Rules::default().is_safe_as_is(numbers)
+}
+
+pub fn is_safe_report_with_dampener(line: &str) -> Result<bool, ParseError> {
+    Ok(is_safe_levels_with_dampener(&parse_numbers(1, line)?))
+}
+
+pub fn is_safe_levels_with_dampener(numbers: &[i32]) -> bool {
+    dampen(numbers).is_safe()
+}
+
+/// Whether a report is safe with the Problem Dampener, and how.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// Safe without removing a level.
+    Safe,
+    /// Safe once the levels at these indices are removed.
+    SafeWithout(Vec<usize>),
+    Unsafe(Reason),
+}
+
+/// Why a report is unsafe even with a level removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    NoLevels,
+    /// The first step against the report's overall direction, or too small
+    /// or large, when no level is removed.
+    Step(BadStep),
+}
+
+/// A step between the levels at two indices that breaks the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadStep {
+    pub from: usize,
+    pub to: usize,
+    pub diff: i64,
+}
+
+impl Verdict {
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Verdict::Unsafe(_))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Safe => write!(f, "safe"),
+            Verdict::SafeWithout(removed) => match &removed[..] {
+                [i] => write!(f, "safe without the level at index {}", i),
+                _ => write!(
+                    f,
+                    "safe without the levels at indices {}",
+                    removed
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            Verdict::Unsafe(Reason::NoLevels) => write!(f, "unsafe: no levels"),
+            Verdict::Unsafe(Reason::Step(step)) => write!(
+                f,
+                "unsafe: the levels at indices {} and {} change by {}",
+                step.from, step.to, step.diff
+            ),
+        }
+    }
+}
+
+/// The dampener's verdict on each report under `rules`, in order.
+pub fn verdicts(reports: &[Vec<i32>], rules: &Rules) -> Vec<Verdict> {
+    reports
+        .iter()
+        .map(|levels| dampen_with(levels, rules))
+        .collect()
+}
+
+/// Decides whether `levels` is safe with at most one level removed, in
+/// linear time.
+pub fn dampen(levels: &[i32]) -> Verdict {
+    dampen_with(levels, &Rules::default())
+}
+
+/// Like [`dampen`] but with other rules, removing up to `rules.max_removals`
+/// levels.
+///
+/// For a given direction, a removal can only fix the first bad step by
+/// taking out one of its two levels, so at most two removals are worth
+/// checking in each direction. Removing more falls back to
+/// [`Rules::removals`], which takes quadratic time.
+pub fn dampen_with(levels: &[i32], rules: &Rules) -> Verdict {
+    if levels.is_empty() {
+        return Verdict::Unsafe(Reason::NoLevels);
+    }
+
+    let mut first_bad = [None; 2];
+    for (bad, sign) in first_bad.iter_mut().zip([1, -1]) {
+        match rules.first_bad_step(levels, sign, None) {
+            None => return Verdict::Safe,
+            found => *bad = found,
+        }
+    }
+    if rules.max_removals >= 1 {
+        for (bad, sign) in first_bad.iter().zip([1, -1]) {
+            let step = bad.expect("checked above");
+            for removed in [step.from, step.to] {
+                if rules.first_bad_step(levels, sign, Some(removed)).is_none() {
+                    return Verdict::SafeWithout(vec![removed]);
+                }
+            }
+        }
+    }
+    if rules.max_removals >= 2 {
+        if let Some(removed) = rules.removals(levels) {
+            if removed.len() <= rules.max_removals {
+                return Verdict::SafeWithout(removed);
+            }
+        }
+    }
+
+    let overall = step(levels[0], levels[levels.len() - 1]);
+    let step = if overall < 0 {
+        first_bad[1]
+    } else {
+        first_bad[0]
+    };
+    Verdict::Unsafe(Reason::Step(step.expect("checked above")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::is_safe_levels;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+
+    /// Reports that are safe without the dampener.
+    fn safe_levels() -> impl Strategy<Value = Vec<i32>> {
+        (0..100i32, vec(1..=3i32, 1..8), any::<bool>()).prop_map(|(start, steps, decreasing)| {
+            let sign = if decreasing { -1 } else { 1 };
+            let mut levels = vec![start];
+            for step in steps {
+                levels.push(levels.last().unwrap() + sign * step);
+            }
+            levels
+        })
+    }
+
+    /// The dampener as the puzzle describes it, trying every removal.
+    fn brute_force(levels: &[i32]) -> bool {
+        is_safe_sequence(levels)
+            || (0..levels.len()).any(|i| {
+                let mut removed = levels.to_vec();
+                removed.remove(i);
+                is_safe_sequence(&removed)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_dampen_matches_brute_force(levels in vec(0..12i32, 0..8)) {
+            let verdict = dampen(&levels);
+            prop_assert_eq!(verdict.is_safe(), brute_force(&levels));
+            if let Verdict::SafeWithout(removed) = verdict {
+                prop_assert_eq!(removed.len(), 1);
+                let mut levels = levels.clone();
+                levels.remove(removed[0]);
+                prop_assert!(is_safe_sequence(&levels));
+            }
+        }
+
+        #[test]
+        fn prop_dampener_accepts_every_safe_report(levels in safe_levels()) {
+            prop_assert!(is_safe_levels(&levels));
+            prop_assert!(is_safe_levels_with_dampener(&levels));
+        }
+
+        #[test]
+        fn prop_dampener_accepts_whatever_part1_does(levels in vec(0..12i32, 0..8)) {
+            if is_safe_levels(&levels) {
+                prop_assert!(is_safe_levels_with_dampener(&levels));
+            }
+        }
+
+        #[test]
+        fn prop_parts_agree_without_removals(levels in vec(0..12i32, 0..8)) {
+            prop_assert_eq!(is_safe_levels(&levels), is_safe_sequence(&levels));
+        }
+
+        #[test]
+        fn prop_one_bad_level_is_tolerated(
+            levels in safe_levels(),
+            bad in -50..150i32,
+            at in any::<Index>(),
+        ) {
+            let mut levels = levels;
+            levels.insert(at.index(levels.len() + 1), bad);
+            prop_assert!(is_safe_levels_with_dampener(&levels));
+        }
+    }
+
+    #[test]
+    fn test_example_cases_with_dampener() {
+        let test_cases = [
+            ("7 6 4 2 1", true),  // Safe without removing any level
+            ("1 2 7 8 9", false), // Unsafe regardless of removal
+            ("9 7 6 2 1", false), // Unsafe regardless of removal
+            ("1 3 2 4 5", true),  // Safe by removing 3
+            ("8 6 4 4 1", true),  // Safe by removing one 4
+            ("1 3 6 7 9", true),  // Safe without removing any level
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                is_safe_report_with_dampener(input).unwrap(),
+                expected,
+                "Failed for input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_example_verdicts() {
+        let verdict = |line: &str| {
+            let levels: Vec<i32> = line.split(' ').map(|n| n.parse().unwrap()).collect();
+            dampen(&levels)
+        };
+        assert_eq!(verdict("7 6 4 2 1"), Verdict::Safe);
+        assert_eq!(verdict("1 3 2 4 5"), Verdict::SafeWithout(vec![1]));
+        assert_eq!(verdict("8 6 4 4 1"), Verdict::SafeWithout(vec![2]));
+        assert_eq!(
+            verdict("1 2 7 8 9").to_string(),
+            "unsafe: the levels at indices 1 and 2 change by 5"
+        );
+        assert_eq!(
+            verdict("9 7 6 2 1"),
+            Verdict::Unsafe(Reason::Step(BadStep {
+                from: 2,
+                to: 3,
+                diff: -4
+            }))
+        );
+        assert_eq!(dampen(&[]), Verdict::Unsafe(Reason::NoLevels));
+    }
+
+    #[test]
+    fn test_dampen_with_removal_limits() {
+        let levels = [1, 2, 7, 8, 9];
+        let rules = |max_removals| Rules {
+            max_removals,
+            ..Rules::default()
+        };
+        assert_eq!(
+            dampen_with(&[1, 3, 2, 4, 5], &rules(0)).to_string(),
+            "unsafe: the levels at indices 1 and 2 change by -1"
+        );
+        assert!(!dampen_with(&levels, &rules(1)).is_safe());
+        let verdict = dampen_with(&levels, &rules(2));
+        assert_eq!(verdict, Verdict::SafeWithout(vec![0, 1]));
+        assert_eq!(
+            verdict.to_string(),
+            "safe without the levels at indices 0, 1"
+        );
+    }
+
+    #[test]
+    fn test_verdicts_of_parsed_reports() {
+        use crate::Day02;
+        use common::Solution;
+
+        let reports = Day02::parse("1 3 2 4 5\n9 7 6 2 1\n").unwrap();
+        let verdicts: Vec<String> = verdicts(&reports, &Rules::default())
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            verdicts,
+            [
+                "safe without the level at index 1",
+                "unsafe: the levels at indices 2 and 3 change by -4"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_full_example_with_dampener() {
+        let input = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+        assert_eq!(count_safe_reports_with_dampener(input), 4);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        assert!(is_safe_report_with_dampener("1").unwrap()); // No steps to be unsafe
+        assert!(!is_safe_report_with_dampener("").unwrap()); // Empty
+        assert!(is_safe_report_with_dampener("1 2").unwrap()); // Minimal valid case
+        assert!(is_safe_report_with_dampener("1 2 -").is_err()); // Not a level
+    }
+}
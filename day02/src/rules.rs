@@ -0,0 +1,207 @@
+use crate::part2::BadStep;
+use crate::step;
+
+/// What makes a report safe: how much each step may change the level and how
+/// many levels may be removed. The default is the puzzle's part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// The smallest change between adjacent levels; 0 lets them be equal.
+    pub min_step: u32,
+    pub max_step: u32,
+    pub max_removals: usize,
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules {
+            min_step: 1,
+            max_step: 3,
+            max_removals: 1,
+        }
+    }
+}
+
+/// How far a report is from being safe under some [`Rules`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assessment {
+    /// Fewest levels to remove to make the report safe, or `None` if it has
+    /// no levels.
+    pub removals: Option<usize>,
+    pub safe: bool,
+}
+
+impl Rules {
+    /// Whether a step of `diff` is allowed in the direction of `sign`.
+    pub fn allows(&self, diff: i64, sign: i64) -> bool {
+        let step = diff * sign;
+        (i64::from(self.min_step)..=i64::from(self.max_step)).contains(&step)
+    }
+
+    /// The first step, skipping the level at index `skip`, that isn't allowed
+    /// in the direction of `sign`.
+    pub fn first_bad_step(
+        &self,
+        levels: &[i32],
+        sign: i64,
+        skip: Option<usize>,
+    ) -> Option<BadStep> {
+        let mut kept = (0..levels.len()).filter(|&i| Some(i) != skip);
+        let mut from = kept.next()?;
+        for to in kept {
+            let diff = step(levels[from], levels[to]);
+            if !self.allows(diff, sign) {
+                return Some(BadStep { from, to, diff });
+            }
+            from = to;
+        }
+        None
+    }
+
+    /// Whether `levels` is safe without removing any.
+    pub fn is_safe_as_is(&self, levels: &[i32]) -> bool {
+        !levels.is_empty()
+            && [1, -1]
+                .iter()
+                .any(|&sign| self.first_bad_step(levels, sign, None).is_none())
+    }
+
+    /// The indices of the fewest levels to remove to leave a safe report,
+    /// keeping at least one. Finds the longest subsequence of levels whose
+    /// steps are all allowed, which takes time quadratic in the report's
+    /// length.
+    pub fn removals(&self, levels: &[i32]) -> Option<Vec<usize>> {
+        if levels.is_empty() {
+            return None;
+        }
+        if self.is_safe_as_is(levels) {
+            return Some(Vec::new());
+        }
+
+        // For each level, the longest chain ending there and the level
+        // before it in that chain
+        let chains = [1, -1].map(|sign| {
+            let mut chain: Vec<(usize, Option<usize>)> = vec![(1, None); levels.len()];
+            for i in 1..levels.len() {
+                for j in 0..i {
+                    if self.allows(step(levels[j], levels[i]), sign) && chain[j].0 + 1 > chain[i].0
+                    {
+                        chain[i] = (chain[j].0 + 1, Some(j));
+                    }
+                }
+            }
+            chain
+        });
+        let (chain, end) = chains
+            .iter()
+            .flat_map(|chain| (0..levels.len()).map(move |end| (chain, end)))
+            .max_by_key(|&(chain, end)| chain[end].0)
+            .expect("levels is not empty");
+
+        let mut kept = vec![false; levels.len()];
+        let mut at = Some(end);
+        while let Some(i) = at {
+            kept[i] = true;
+            at = chain[i].1;
+        }
+        Some((0..levels.len()).filter(|&i| !kept[i]).collect())
+    }
+
+    /// The number of [`Rules::removals`].
+    pub fn min_removals(&self, levels: &[i32]) -> Option<usize> {
+        self.removals(levels).map(|removals| removals.len())
+    }
+
+    pub fn assess(&self, levels: &[i32]) -> Assessment {
+        let removals = self.min_removals(levels);
+        Assessment {
+            removals,
+            safe: removals.is_some_and(|removals| removals <= self.max_removals),
+        }
+    }
+
+    pub fn is_safe(&self, levels: &[i32]) -> bool {
+        self.assess(levels).safe
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part2::{dampen_with, is_safe_levels_with_dampener, Verdict};
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_default_rules_match_the_dampener(levels in vec(0..12i32, 0..8)) {
+            prop_assert_eq!(Rules::default().is_safe(&levels), is_safe_levels_with_dampener(&levels));
+        }
+
+        #[test]
+        fn prop_dampener_honours_max_removals(
+            levels in vec(0..12i32, 0..8),
+            max_removals in 0..4usize,
+        ) {
+            let rules = Rules { max_removals, ..Rules::default() };
+            let verdict = dampen_with(&levels, &rules);
+            prop_assert_eq!(verdict.is_safe(), rules.is_safe(&levels));
+            if let Verdict::SafeWithout(removed) = verdict {
+                prop_assert!(removed.len() <= max_removals);
+                let kept: Vec<i32> = (0..levels.len())
+                    .filter(|i| !removed.contains(i))
+                    .map(|i| levels[i])
+                    .collect();
+                prop_assert!(rules.is_safe_as_is(&kept));
+            }
+        }
+    }
+
+    #[test]
+    fn test_min_removals() {
+        let rules = Rules::default();
+        assert_eq!(rules.min_removals(&[7, 6, 4, 2, 1]), Some(0));
+        assert_eq!(rules.min_removals(&[1, 3, 2, 4, 5]), Some(1));
+        // Keeping 1 2 or 8 9
+        assert_eq!(rules.min_removals(&[1, 2, 7, 8, 9]), Some(2));
+        assert_eq!(rules.min_removals(&[5]), Some(0));
+        assert_eq!(rules.min_removals(&[]), None);
+    }
+
+    #[test]
+    fn test_configured_rules() {
+        let wide = Rules {
+            max_step: 5,
+            ..Rules::default()
+        };
+        assert!(wide.is_safe_as_is(&[1, 2, 7, 8, 9]));
+
+        let level = Rules {
+            min_step: 0,
+            max_removals: 0,
+            ..Rules::default()
+        };
+        assert!(level.is_safe(&[8, 6, 4, 4, 1]));
+        assert!(level.allows(0, 1) && level.allows(0, -1));
+        assert!(!Rules::default().is_safe_as_is(&[8, 6, 4, 4, 1]));
+
+        let two_removals = Rules {
+            max_removals: 2,
+            ..Rules::default()
+        };
+        assert_eq!(
+            two_removals.assess(&[1, 2, 7, 8, 9]),
+            Assessment {
+                removals: Some(2),
+                safe: true
+            }
+        );
+        assert!(!Rules::default().is_safe(&[1, 2, 7, 8, 9]));
+
+        let large_steps = Rules {
+            min_step: 2,
+            max_step: 4,
+            ..Rules::default()
+        };
+        assert_eq!(large_steps.min_removals(&[1, 2, 4, 6]), Some(1));
+    }
+}
@@ -0,0 +1,37 @@
+use rand::Rng;
+use std::fmt::Write;
+
+/// Corrupted memory holding `size` instructions, valid and otherwise,
+/// separated by junk characters. There is always at least one, since the
+/// memory can't be blank.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    const JUNK: &[u8] = b"~!@#$%^&*()[]{}<>+-?/:;', mul";
+    const DECOYS: [&str; 5] = [
+        "mul(4*",
+        "mul ( 2 , 4 )",
+        "mul[3,7]",
+        "do_not()",
+        "mul(1234,5)",
+    ];
+
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        for _ in 0..rng.gen_range(0..8) {
+            input.push(JUNK[rng.gen_range(0..JUNK.len())] as char);
+        }
+        match rng.gen_range(0..10) {
+            0 => input.push_str("do()"),
+            1 => input.push_str("don't()"),
+            2 => input.push_str(DECOYS[rng.gen_range(0..DECOYS.len())]),
+            _ => write!(
+                input,
+                "mul({},{})",
+                rng.gen_range(0..1000),
+                rng.gen_range(0..1000)
+            )
+            .unwrap(),
+        }
+    }
+    input.push('\n');
+    input
+}
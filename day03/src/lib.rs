@@ -0,0 +1,99 @@
+use common::{ParseError, Solution};
+use regex::Regex;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub operation_type: InstructionType,
+    pub position: usize,
+}
+
+#[derive(Debug)]
+pub enum InstructionType {
+    Multiply(i32, i32),
+    Do,
+    Dont,
+}
+
+/// Finds every `mul`, `do` and `don't` instruction in the corrupted memory, in order.
+/// Anything else is corruption to skip, so this cannot fail.
+pub fn parse_instructions(input: &str) -> Vec<Instruction> {
+    // Create regex patterns for all instruction types
+    let mul_pattern = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+    let do_pattern = Regex::new(r"do\(\)").unwrap();
+    let dont_pattern = Regex::new(r"don't\(\)").unwrap();
+
+    // Collect all instructions with their positions
+    let mut instructions = Vec::new();
+
+    // Find multiplication instructions
+    for cap in mul_pattern.captures_iter(input) {
+        // At most three digits, so these always fit
+        let x: i32 = cap[1].parse().unwrap();
+        let y: i32 = cap[2].parse().unwrap();
+        let pos = cap.get(0).unwrap().start();
+        instructions.push(Instruction {
+            operation_type: InstructionType::Multiply(x, y),
+            position: pos,
+        });
+    }
+
+    // Find do() instructions
+    for m in do_pattern.find_iter(input) {
+        instructions.push(Instruction {
+            operation_type: InstructionType::Do,
+            position: m.start(),
+        });
+    }
+
+    // Find don't() instructions
+    for m in dont_pattern.find_iter(input) {
+        instructions.push(Instruction {
+            operation_type: InstructionType::Dont,
+            position: m.start(),
+        });
+    }
+
+    // Sort instructions by position
+    instructions.sort_by_key(|i| i.position);
+
+    instructions
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_instructions(input))
+    }
+
+    fn part1(instructions: &Self::Input) -> i32 {
+        part1::sum_multiplications(instructions)
+    }
+
+    fn part2(instructions: &Self::Input) -> i32 {
+        part2::sum_enabled_multiplications(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let instructions = Day03::parse(input).unwrap();
+        assert_eq!(Day03::part1(&instructions), 161);
+        assert_eq!(Day03::part2(&instructions), 48);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{Instruction, InstructionType};
+use regex::Regex;
+
+pub fn solve(input: &str) -> i32 {
+    // Create a regex pattern for valid mul instructions
+    // Matches exactly mul(X,Y) where X and Y are 1-3 digits
+    let pattern = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+    // Find all valid matches and calculate sum
+    pattern
+        .captures_iter(input)
+        .map(|cap| {
+            let x: i32 = cap[1].parse().unwrap();
+            let y: i32 = cap[2].parse().unwrap();
+            x * y
+        })
+        .sum()
+}
+
+pub fn sum_multiplications(instructions: &[Instruction]) -> i32 {
+    instructions
+        .iter()
+        .map(|instruction| match instruction.operation_type {
+            InstructionType::Multiply(x, y) => x * y,
+            InstructionType::Do | InstructionType::Dont => 0,
+        })
+        .sum()
+}
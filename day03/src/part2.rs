@@ -0,0 +1,30 @@
+use crate::{parse_instructions, Instruction, InstructionType};
+
+pub fn solve(input: &str) -> i32 {
+    let instructions = parse_instructions(input);
+    sum_enabled_multiplications(&instructions)
+}
+
+pub fn sum_enabled_multiplications(instructions: &[Instruction]) -> i32 {
+    // Process instructions in order
+    let mut sum = 0;
+    let mut multiplications_enabled = true;
+
+    for instruction in instructions {
+        match instruction.operation_type {
+            InstructionType::Multiply(x, y) => {
+                if multiplications_enabled {
+                    sum += x * y;
+                }
+            }
+            InstructionType::Do => {
+                multiplications_enabled = true;
+            }
+            InstructionType::Dont => {
+                multiplications_enabled = false;
+            }
+        }
+    }
+
+    sum
+}
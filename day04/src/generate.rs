@@ -0,0 +1,13 @@
+use rand::Rng;
+
+/// A `size` by `size` word search of the letters X, M, A and S.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(b"XMAS"[rng.gen_range(0..4)] as char);
+        }
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,33 @@
+use common::{Grid, ParseError, Solution};
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+/// Parses the word search; strict parsing only allows the letters of XMAS
+/// and the `.` filler used in the examples.
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_chars(input, |c| matches!(c, 'X' | 'M' | 'A' | 'S' | '.'))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        part1::count_xmas_occurrences(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        part2::count_xmas_patterns(grid)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::parse_input;
+use common::{Direction, Grid, Point};
+
+pub fn solve(input: &str) -> usize {
+    let grid = parse_input(input).expect("Failed to parse input");
+    count_xmas_occurrences(&grid)
+}
+
+pub fn count_xmas_occurrences(grid: &Grid<char>) -> usize {
+    // Search from each starting position, in all eight directions
+    grid.points()
+        .map(|start| {
+            Direction::ALL
+                .iter()
+                .filter(|&&direction| check_xmas(grid, start, direction))
+                .count()
+        })
+        .sum()
+}
+
+fn check_xmas(grid: &Grid<char>, start: Point, direction: Direction) -> bool {
+    let target = ['X', 'M', 'A', 'S'];
+
+    // The ray stops at the edge of the grid, so a word that doesn't fit comes up short
+    let word: Vec<char> = grid
+        .ray(start, direction)
+        .take(target.len())
+        .map(|(_, &c)| c)
+        .collect();
+
+    word == target
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Grid::from_rows(vec![
+            "MMMSXXMASM".chars().collect(),
+            "MSAMXMSMSA".chars().collect(),
+            "AMXSXMAAMM".chars().collect(),
+            "MSAMASMSMX".chars().collect(),
+            "XMASAMXAMM".chars().collect(),
+            "XXAMMXXAMA".chars().collect(),
+            "SMSMSASXSS".chars().collect(),
+            "SAXAMASAAA".chars().collect(),
+            "MAMMMXMMMM".chars().collect(),
+            "MXMXAXMASX".chars().collect(),
+        ])
+        .unwrap();
+
+        assert_eq!(count_xmas_occurrences(&input), 18);
+    }
+}
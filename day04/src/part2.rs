@@ -0,0 +1,93 @@
+use crate::parse_input;
+use common::{Direction, Grid, Point};
+
+pub fn solve(input: &str) -> usize {
+    let grid = parse_input(input).expect("Failed to parse input");
+    count_xmas_patterns(&grid)
+}
+
+pub fn count_xmas_patterns(grid: &Grid<char>) -> usize {
+    // Every 'A' is a possible center point of the X
+    grid.positions(|&c| c == 'A')
+        .map(|center| check_x_pattern(grid, center))
+        .sum()
+}
+
+fn check_x_pattern(grid: &Grid<char>, center: Point) -> usize {
+    let mut valid_patterns = 0;
+
+    // Corners off the edge of the grid can't be part of an X
+    let corner = |direction: Direction| grid.get(center.step(direction)).copied();
+    let (Some(up_left), Some(up_right), Some(down_left), Some(down_right)) = (
+        corner(Direction::UpLeft),
+        corner(Direction::UpRight),
+        corner(Direction::DownLeft),
+        corner(Direction::DownRight),
+    ) else {
+        return 0;
+    };
+
+    // Check all possible combinations of forward/backward MAS on each diagonal
+    let top_left = [up_left, grid[center], down_right];
+    let top_right = [up_right, grid[center], down_left];
+
+    // For each diagonal, check both forward and backward MAS
+    let valid_top_left_forward = check_mas(&top_left, false);
+    let valid_top_left_backward = check_mas(&top_left, true);
+    let valid_top_right_forward = check_mas(&top_right, false);
+    let valid_top_right_backward = check_mas(&top_right, true);
+
+    // Count valid combinations
+    if valid_top_left_forward {
+        if valid_top_right_forward {
+            valid_patterns += 1;
+        }
+        if valid_top_right_backward {
+            valid_patterns += 1;
+        }
+    }
+    if valid_top_left_backward {
+        if valid_top_right_forward {
+            valid_patterns += 1;
+        }
+        if valid_top_right_backward {
+            valid_patterns += 1;
+        }
+    }
+
+    valid_patterns
+}
+
+fn check_mas(chars: &[char], backward: bool) -> bool {
+    if backward {
+        chars[0] == 'S' && chars[1] == 'A' && chars[2] == 'M'
+    } else {
+        chars[0] == 'M' && chars[1] == 'A' && chars[2] == 'S'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Grid::from_rows(vec![
+            "MMMSXXMASM".chars().collect(),
+            "MSAMXMSMSA".chars().collect(),
+            "AMXSXMAAMM".chars().collect(),
+            "MSAMASMSMX".chars().collect(),
+            "XMASAMXAMM".chars().collect(),
+            "XXAMMXXAMA".chars().collect(),
+            "SMSMSASXSS".chars().collect(),
+            "SAXAMASAAA".chars().collect(),
+            "MAMMMXMMMM".chars().collect(),
+            "MXMXAXMASX".chars().collect(),
+        ])
+        .unwrap();
+
+        let count = count_xmas_patterns(&input);
+        println!("Found {} patterns", count); // For debugging
+        assert_eq!(count, 9); // Based on the example output
+    }
+}
@@ -0,0 +1,31 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt::Write;
+
+/// Page ordering rules for up to 89 pages, giving the order of every pair as
+/// the puzzle does, then `size` updates of odd length, at least one as the
+/// input must have some. About half of the updates are already in order.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut pages: Vec<u32> = (10..100).collect();
+    pages.shuffle(rng);
+    pages.truncate(size.clamp(5, pages.len()));
+
+    let mut input = String::new();
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            writeln!(input, "{}|{}", before, after).unwrap();
+        }
+    }
+    input.push('\n');
+
+    for _ in 0..size.max(1) {
+        let length = rng.gen_range(0..=(pages.len() - 1) / 2) * 2 + 1;
+        let mut update: Vec<usize> = rand::seq::index::sample(rng, pages.len(), length).into_vec();
+        if rng.gen_bool(0.5) {
+            update.sort_unstable();
+        }
+        let update: Vec<String> = update.iter().map(|&i| pages[i].to_string()).collect();
+        writeln!(input, "{}", update.join(",")).unwrap();
+    }
+    input
+}
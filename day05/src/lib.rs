@@ -0,0 +1,174 @@
+use common::error::{column_of, parse_number};
+use common::mode::is_strict;
+use common::{ParseError, Solution};
+use std::str::FromStr;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct Rule {
+    pub before: u32,
+    pub after: u32,
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    /// Parses a single `X|Y` rule; errors are reported on line 1.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((before, after)) = s.split_once('|') else {
+            return Err(ParseError::Malformed {
+                line: 1,
+                column: 1,
+                text: s.to_string(),
+                expected: "a rule like 47|53",
+            });
+        };
+
+        Ok(Rule {
+            before: parse_number(1, s, before)?,
+            after: parse_number(1, s, after)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Update {
+    pub pages: Vec<u32>,
+}
+
+impl FromStr for Update {
+    type Err = ParseError;
+
+    /// Parses a single comma separated update; errors are reported on line 1.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pages = Vec::new();
+        for n in s.split(',') {
+            let page = parse_number(1, s, n)?;
+            if pages.contains(&page) {
+                return Err(ParseError::Malformed {
+                    line: 1,
+                    column: column_of(s, n),
+                    text: n.to_string(),
+                    expected: "each page at most once per update",
+                });
+            }
+            pages.push(page);
+        }
+        Ok(Update { pages })
+    }
+}
+
+/// Splits the input into its page ordering rules and the updates to check.
+pub fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Update>), ParseError> {
+    let mut rules = Vec::new();
+    let mut updates = Vec::new();
+    let mut in_updates = false;
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            if in_updates && is_strict() {
+                return Err(ParseError::Malformed {
+                    line: i + 1,
+                    column: 1,
+                    text: String::new(),
+                    expected: "a single blank line between the rules and the updates",
+                });
+            }
+            // The first blank line after the rules starts the updates
+            in_updates |= !rules.is_empty();
+            continue;
+        }
+
+        let on_line = |e: ParseError| e.on_line(i + 1);
+        if in_updates {
+            updates.push(line.parse().map_err(on_line)?);
+        } else {
+            rules.push(line.parse().map_err(on_line)?);
+        }
+    }
+
+    if rules.is_empty() {
+        return Err(ParseError::Missing("page ordering rules".to_string()));
+    }
+    if !in_updates {
+        return Err(ParseError::Missing("updates".to_string()));
+    }
+
+    Ok((rules, updates))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<Rule>, Vec<Update>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((rules, updates): &Self::Input) -> u64 {
+        part1::sum_valid_middles(rules, updates)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> u64 {
+        part2::sum_fixed_middles(rules, updates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bad_lines_are_reported() {
+        let error = parse_input("47|53\n97-13\n\n75,47,61\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 1: expected a rule like 47|53, found \"97-13\""
+        );
+
+        let error = parse_input("47|53\n\n75,47,61\n75,x\n").unwrap_err();
+        assert_eq!(error.to_string(), "Line 4, column 4: invalid number \"x\"");
+    }
+
+    #[test]
+    fn test_repeated_pages_are_rejected() {
+        let error = parse_input("47|53\n\n75,47,75\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 7: expected each page at most once per update, found \"75\""
+        );
+    }
+
+    #[test]
+    fn test_large_pages() {
+        let (rules, updates) =
+            parse_input("1|4294967295\n\n1,4294967295,2\n1,4294967295,3\n").unwrap();
+        assert_eq!(part1::sum_valid_middles(&rules, &updates), 2 * 4294967295);
+    }
+
+    #[test]
+    fn test_extra_sections_are_rejected_when_strict() {
+        use common::mode::{with_parse_mode, ParseMode};
+
+        let input = "47|53\n\n75,47,61\n\n75,53\n";
+        let lenient = with_parse_mode(ParseMode::Lenient, || parse_input(input)).unwrap();
+        assert_eq!(lenient.1.len(), 2);
+        let error = with_parse_mode(ParseMode::Strict, || parse_input(input)).unwrap_err();
+        assert_eq!(error.line(), Some(4));
+    }
+
+    #[test]
+    fn test_missing_sections() {
+        let error = parse_input("47|53\n").unwrap_err();
+        assert_eq!(error.to_string(), "Missing updates");
+        assert!(parse_input("").is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::State;
+use common::{Direction, Grid, Point};
+use rand::Rng;
+use std::collections::HashSet;
+
+/// A `size` by `size` map with roughly one obstruction in ten and the guard
+/// facing up somewhere in the open. As in the puzzle, the guard's patrol
+/// always leads off the map.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let cells = loop {
+        let mut cells: Vec<char> = (0..size * size)
+            .map(|_| if rng.gen_bool(0.1) { '#' } else { '.' })
+            .collect();
+        let guard = rng.gen_range(0..cells.len());
+        cells[guard] = '^';
+        if leaves_map(&cells, size, guard) {
+            break cells;
+        }
+    };
+
+    let mut input = String::new();
+    for row in cells.chunks(size) {
+        input.extend(row);
+        input.push('\n');
+    }
+    input
+}
+
+fn leaves_map(cells: &[char], size: usize, guard: usize) -> bool {
+    let rows = cells.chunks(size).map(|row| row.to_vec()).collect();
+    let grid = Grid::from_rows(rows).expect("rows are all the same width");
+    let mut state = State {
+        position: Point::new((guard / size) as i32, (guard % size) as i32),
+        direction: Direction::Up,
+    };
+    let mut seen = HashSet::new();
+
+    while seen.insert(state) {
+        let next = state.position.step(state.direction);
+        match grid.get(next) {
+            None => return true,
+            Some('#') => state.direction = state.direction.turn_right(),
+            Some(_) => state.position = next,
+        }
+    }
+    false
+}
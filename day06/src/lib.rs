@@ -0,0 +1,71 @@
+use common::{Direction, Grid, ParseError, Point, Solution};
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod visualise;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct State {
+    pub position: Point,
+    pub direction: Direction,
+}
+
+pub fn parse_input(input: &str) -> Result<(State, Grid<char>), ParseError> {
+    let grid = Grid::parse_chars(input, |c| matches!(c, '.' | '#' | '^'))?;
+
+    let guards: Vec<Point> = grid.positions(|&c| c == '^').collect();
+    let position = *guards
+        .first()
+        .ok_or_else(|| ParseError::Missing("guard (^) in the map".to_string()))?;
+    if let Some(extra) = guards.get(1) {
+        return Err(ParseError::UnexpectedChar {
+            line: extra.row as usize + 1,
+            column: extra.col as usize + 1,
+            found: '^',
+        });
+    }
+    let guard_state = State {
+        position,
+        direction: Direction::Up,
+    };
+
+    Ok((guard_state, grid))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = (State, Grid<char>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((start, grid): &Self::Input) -> usize {
+        part1::simulate_guard_path(*start, grid)
+    }
+
+    fn part2((start, grid): &Self::Input) -> usize {
+        part2::find_loop_positions(*start, grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guards_must_be_unique() {
+        let error = parse_input("..^\n.^.\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 2: unexpected character '^'"
+        );
+        assert!(parse_input("...\n").is_err());
+    }
+}
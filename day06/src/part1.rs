@@ -0,0 +1,72 @@
+use crate::{parse_input, State};
+use common::Grid;
+use std::collections::HashSet;
+
+pub fn solve(input: &str) -> usize {
+    let (start, grid) = parse_input(input).expect("Failed to parse input");
+    simulate_guard_path(start, &grid)
+}
+
+/// The number of distinct positions the guard visits. A guard that never
+/// leaves the map, walking a loop or boxed in, stops once it is back in a
+/// state it has been in before.
+pub fn simulate_guard_path(start: State, grid: &Grid<char>) -> usize {
+    let mut visited = HashSet::new();
+    let mut states = HashSet::new();
+    let mut position = start.position;
+    let mut dir = start.direction;
+
+    visited.insert(position);
+
+    while states.insert((position, dir)) {
+        let next = position.step(dir);
+
+        let Some(&tile) = grid.get(next) else {
+            // We're about to step out of bounds - stop here
+            break;
+        };
+
+        if tile == '#' {
+            // Hit obstacle - turn right and continue
+            dir = dir.turn_right();
+        } else {
+            // Move forward
+            position = next;
+            visited.insert(position);
+        }
+    }
+
+    visited.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+        let (start, grid) = parse_input(input).unwrap();
+        let result = simulate_guard_path(start, &grid);
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn test_trapped_guard_stops() {
+        let (start, grid) = parse_input(".#.\n#^#\n.#.\n").unwrap();
+        assert_eq!(simulate_guard_path(start, &grid), 1);
+
+        let (start, grid) = parse_input(".#..\n...#\n#^..\n..#.\n").unwrap();
+        assert_eq!(simulate_guard_path(start, &grid), 4);
+    }
+}
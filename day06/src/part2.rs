@@ -0,0 +1,101 @@
+use crate::{parse_input, State};
+use common::{Grid, Point};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+pub fn solve(input: &str) -> usize {
+    let (start, grid) = parse_input(input).expect("Failed to parse input");
+    find_loop_positions(start, &grid)
+}
+
+fn check_position_creates_loop(start: State, test_pos: Point, grid: &Grid<char>) -> bool {
+    let mut visited_states = HashSet::new();
+    let mut path = Vec::new(); // Keep track of the path for verification
+    let mut current = start;
+    let max_steps = grid.width() * grid.height() * 4;
+    let mut steps = 0;
+
+    while steps < max_steps {
+        steps += 1;
+
+        // Store the current state in path
+        path.push(current);
+
+        // Check if we've seen this state before
+        if !visited_states.insert(current) {
+            // Found a potential loop - verify it's a valid one
+            let loop_start_idx = path.iter().position(|&state| state == current).unwrap();
+
+            // Check if any position in the loop would exit the grid
+            let loop_segment = &path[loop_start_idx..];
+            for state in loop_segment {
+                if !grid.contains(state.position.step(state.direction)) {
+                    return false;
+                }
+            }
+            return true;
+        }
+
+        // Calculate next position
+        let next = current.position.step(current.direction);
+
+        // Check if we would exit the grid
+        let Some(&tile) = grid.get(next) else {
+            return false;
+        };
+
+        // Check if we hit an obstacle or test position
+        if tile == '#' || next == test_pos {
+            // Turn right
+            current.direction = current.direction.turn_right();
+        } else {
+            // Move forward
+            current.position = next;
+        }
+    }
+
+    false
+}
+
+pub fn find_loop_positions(start: State, grid: &Grid<char>) -> usize {
+    loop_positions(start, grid).len()
+}
+
+/// Where a new obstruction would trap the guard in a loop.
+pub fn loop_positions(start: State, grid: &Grid<char>) -> Vec<Point> {
+    // Pre-calculate empty positions to test
+    let empty_positions: Vec<Point> = grid
+        .positions(|&tile| tile == '.')
+        .filter(|&pos| pos != start.position)
+        .collect();
+
+    // Process positions in parallel
+    empty_positions
+        .par_iter()
+        .filter(|&&pos| check_position_creates_loop(start, pos, grid))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+        let (start, grid) = parse_input(input).unwrap();
+        let result = find_loop_positions(start, &grid);
+        assert_eq!(result, 6);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{part2, Day06, State};
+use common::render::{Colour, Frame, Visualise};
+use common::Direction;
+use std::collections::HashSet;
+use std::iter;
+
+/// The guard's path so far, drawn with the direction it was last walked in,
+/// then the positions where an obstruction would trap it in a loop.
+impl Visualise for Day06 {
+    fn frames((start, grid): Self::Input) -> Box<dyn Iterator<Item = Frame>> {
+        let mut path = Frame::new(&grid, |&tile| if tile == '^' { '.' } else { tile });
+        path.overlay(grid.positions(|&tile| tile == '#'), None, Colour::GREY);
+
+        let mut visited = HashSet::new();
+        let mut seen = HashSet::new();
+        let mut guard = Some(start);
+        let mut loops_shown = false;
+
+        Box::new(iter::from_fn(move || {
+            let Some(state) = guard.take() else {
+                if loops_shown {
+                    return None;
+                }
+                loops_shown = true;
+                let loops = part2::loop_positions(start, &grid);
+                let mut frame = path.clone();
+                frame.overlay(loops.iter().copied(), Some('O'), Colour::CYAN);
+                return Some(frame.with_caption(format!(
+                    "{} positions visited, {} where an obstruction makes a loop",
+                    visited.len(),
+                    loops.len()
+                )));
+            };
+            visited.insert(state.position);
+            path.overlay(
+                [state.position],
+                Some(arrow(state.direction)),
+                Colour::YELLOW,
+            );
+
+            let next = state.position.step(state.direction);
+            let outcome = match grid.get(next) {
+                None => "and leaves the map",
+                Some(_) if !seen.insert(state) => "and is stuck in a loop",
+                Some('#') => {
+                    guard = Some(State {
+                        direction: state.direction.turn_right(),
+                        ..state
+                    });
+                    "and turns"
+                }
+                Some(_) => {
+                    guard = Some(State {
+                        position: next,
+                        ..state
+                    });
+                    "and walks on"
+                }
+            };
+
+            let mut frame = path.clone();
+            frame.overlay([state.position], Some('@'), Colour::RED);
+            Some(frame.with_caption(format!("{} positions visited, {}", visited.len(), outcome)))
+        }))
+    }
+}
+
+fn arrow(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+        _ => '*',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_frames_follow_the_guard_out() {
+        let input = Day06::parse(".#..\n...#\n.^..\n").unwrap();
+        let frames: Vec<Frame> = Day06::frames(input).collect();
+        assert_eq!(frames.len(), 7);
+        assert_eq!(
+            frames[5].to_string(),
+            ".#..\n.>v#\n.^@.\n4 positions visited, and leaves the map\n"
+        );
+        assert_eq!(
+            frames[6].caption,
+            "4 positions visited, 0 where an obstruction makes a loop"
+        );
+    }
+}
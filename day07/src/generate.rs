@@ -0,0 +1,28 @@
+use rand::Rng;
+use std::fmt::Write;
+
+/// `size` calibration equations of two to eight numbers. Most test values
+/// are made from the numbers with the puzzle's operators, the rest are
+/// random.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let numbers: Vec<u64> = (0..rng.gen_range(2..=8))
+            .map(|_| rng.gen_range(1..100))
+            .collect();
+        let test_value = if rng.gen_bool(0.8) {
+            numbers[1..]
+                .iter()
+                .fold(numbers[0], |value, &n| match rng.gen_range(0..3) {
+                    0 => value + n,
+                    1 => value * n,
+                    _ => format!("{}{}", value, n).parse().unwrap(),
+                })
+        } else {
+            rng.gen_range(1..1_000_000)
+        };
+        let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        writeln!(input, "{}: {}", test_value, numbers.join(" ")).unwrap();
+    }
+    input
+}
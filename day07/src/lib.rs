@@ -0,0 +1,114 @@
+use common::error::parse_number;
+use common::mode::content_lines;
+use common::{ParseError, Solution};
+use std::str::FromStr;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct Equation {
+    pub test_value: i64,
+    pub numbers: Vec<i64>,
+}
+
+impl FromStr for Equation {
+    type Err = ParseError;
+
+    /// Parses a single `test: numbers...` line; errors are reported on line 1.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((test_value, numbers)) = s.split_once(':') else {
+            return Err(ParseError::Malformed {
+                line: 1,
+                column: 1,
+                text: s.to_string(),
+                expected: "an equation like 190: 10 19",
+            });
+        };
+
+        let numbers: Vec<i64> = numbers
+            .split_whitespace()
+            .map(|n| parse_number(1, s, n))
+            .collect::<Result<_, _>>()?;
+        if numbers.is_empty() {
+            return Err(ParseError::Malformed {
+                line: 1,
+                column: s.chars().count() + 1,
+                text: s.to_string(),
+                expected: "at least one number after the colon",
+            });
+        }
+
+        Ok(Equation {
+            test_value: parse_number(1, s, test_value.trim())?,
+            numbers,
+        })
+    }
+}
+
+/// Parses one equation per line.
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    content_lines(input)
+        .map(|line| {
+            let (number, line) = line?;
+            Equation::from_str(line).map_err(|e| e.on_line(number))
+        })
+        .collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(equations: &Self::Input) -> i64 {
+        equations
+            .iter()
+            .filter(|equation| part1::can_equation_be_true(equation))
+            .map(|equation| equation.test_value)
+            .sum()
+    }
+
+    fn part2(equations: &Self::Input) -> i64 {
+        equations
+            .iter()
+            .filter(|equation| part2::can_equation_be_true(equation))
+            .map(|equation| equation.test_value)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bad_equations_are_reported() {
+        let error = parse_input("190: 10 19\n3267 81 40 27\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 1: expected an equation like 190: 10 19, found \"3267 81 40 27\""
+        );
+
+        let error = parse_input("190: 10 19\n1: \n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 4: expected at least one number after the colon, found \"1: \""
+        );
+
+        let error = parse_input("190: 10 19\n83: 17 five\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 8: invalid number \"five\""
+        );
+    }
+}
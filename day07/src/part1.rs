@@ -0,0 +1,100 @@
+use crate::{parse_input, Equation};
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    /// `None` if the result does not fit, in which case it cannot be a test value.
+    fn apply(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Multiply => a.checked_mul(b),
+        }
+    }
+}
+
+const OPERATORS: [Operator; 2] = [Operator::Add, Operator::Multiply];
+
+/// Whether some choice of operators between `rest`, applied left to right
+/// after `value`, gives `target`. Searches depth first, so only one choice
+/// per number is held at a time and results too large to fit are dropped.
+fn reaches(value: i64, rest: &[i64], target: i64) -> bool {
+    match rest.split_first() {
+        None => value == target,
+        Some((&n, rest)) => OPERATORS.iter().any(|operator| {
+            operator
+                .apply(value, n)
+                .is_some_and(|value| reaches(value, rest, target))
+        }),
+    }
+}
+
+pub fn can_equation_be_true(equation: &Equation) -> bool {
+    equation
+        .numbers
+        .split_first()
+        .is_some_and(|(&first, rest)| reaches(first, rest, equation.test_value))
+}
+
+pub fn solve(input: &str) -> i64 {
+    parse_input(input)
+        .expect("Failed to parse input")
+        .iter()
+        .filter(|equation| can_equation_be_true(equation))
+        .map(|equation| equation.test_value)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_example() {
+        let input = r#"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20"#;
+
+        assert_eq!(solve(input), 3749);
+    }
+
+    #[test]
+    fn test_equation_parsing() {
+        let eq = Equation::from_str("190: 10 19").unwrap();
+        assert_eq!(eq.test_value, 190);
+        assert_eq!(eq.numbers, vec![10, 19]);
+    }
+
+    #[test]
+    fn test_evaluation() {
+        assert_eq!(Operator::Multiply.apply(10, 19), Some(190));
+        assert_eq!(Operator::Add.apply(10, 19), Some(29));
+    }
+
+    #[test]
+    fn test_overflow_is_not_a_match() {
+        assert_eq!(Operator::Multiply.apply(i64::MAX, 2), None);
+        assert!(!can_equation_be_true(&Equation {
+            test_value: -2,
+            numbers: vec![i64::MAX, 2],
+        }));
+    }
+
+    #[test]
+    fn test_many_numbers() {
+        // Once crashed counting 2^21 operator combinations in an i32
+        let ones = vec!["1"; 22].join(" ");
+        assert_eq!(solve(&format!("22: {}", ones)), 22);
+        assert_eq!(solve(&format!("23: {}", ones)), 0);
+    }
+}
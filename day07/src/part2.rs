@@ -0,0 +1,132 @@
+use crate::{parse_input, Equation};
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operator {
+    /// `None` if the result does not fit, in which case it cannot be a test value.
+    fn apply(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Multiply => a.checked_mul(b),
+            Operator::Concat => {
+                let b_str = b.to_string();
+                let combined = format!("{}{}", a, b_str);
+                combined.parse().ok()
+            }
+        }
+    }
+}
+
+const OPERATORS: [Operator; 3] = [Operator::Add, Operator::Multiply, Operator::Concat];
+
+/// Whether some choice of operators between `rest`, applied left to right
+/// after `value`, gives `target`. Searches depth first, so only one choice
+/// per number is held at a time and results too large to fit are dropped.
+fn reaches(value: i64, rest: &[i64], target: i64) -> bool {
+    match rest.split_first() {
+        None => value == target,
+        Some((&n, rest)) => OPERATORS.iter().any(|operator| {
+            operator
+                .apply(value, n)
+                .is_some_and(|value| reaches(value, rest, target))
+        }),
+    }
+}
+
+pub fn can_equation_be_true(equation: &Equation) -> bool {
+    equation
+        .numbers
+        .split_first()
+        .is_some_and(|(&first, rest)| reaches(first, rest, equation.test_value))
+}
+
+pub fn solve(input: &str) -> i64 {
+    parse_input(input)
+        .expect("Failed to parse input")
+        .iter()
+        .filter(|equation| can_equation_be_true(equation))
+        .map(|equation| equation.test_value)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Equations whose test value is made from their numbers with `+` and
+    /// `*` only, or is an arbitrary number.
+    fn equations() -> impl Strategy<Value = Equation> {
+        (
+            vec(1..50i64, 2..7),
+            any::<u32>(),
+            prop::option::of(1..100_000i64),
+        )
+            .prop_map(|(numbers, mut operators, arbitrary)| {
+                let made = numbers[1..].iter().fold(numbers[0], |value, &n| {
+                    operators >>= 1;
+                    if operators & 1 == 0 {
+                        value + n
+                    } else {
+                        value * n
+                    }
+                });
+                Equation {
+                    test_value: arbitrary.unwrap_or(made),
+                    numbers,
+                }
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_part2_accepts_whatever_part1_does(equation in equations()) {
+            if part1::can_equation_be_true(&equation) {
+                prop_assert!(can_equation_be_true(&equation));
+            }
+        }
+
+        #[test]
+        fn prop_part2_total_is_at_least_part1(equations in vec(equations(), 0..10)) {
+            let total = |can: fn(&Equation) -> bool| -> i64 {
+                equations.iter().filter(|e| can(e)).map(|e| e.test_value).sum()
+            };
+            prop_assert!(total(can_equation_be_true) >= total(part1::can_equation_be_true));
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        let input = r#"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20"#;
+
+        assert_eq!(solve(input), 11387);
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(Operator::Concat.apply(15, 6), Some(156));
+        assert_eq!(Operator::Concat.apply(i64::MAX, 1), None);
+    }
+
+    #[test]
+    fn test_many_numbers() {
+        // Once crashed counting 3^21 operator combinations in an i32
+        let ones = vec!["1"; 22].join(" ");
+        assert_eq!(solve(&format!("22: {}", ones)), 22);
+    }
+}
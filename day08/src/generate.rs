@@ -0,0 +1,20 @@
+use rand::Rng;
+
+/// A `size` by `size` map with about one antenna in twenty cells, using a
+/// handful of frequencies so each has several antennas.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0aAbBzZ9";
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            let cell = if rng.gen_bool(0.05) {
+                FREQUENCIES[rng.gen_range(0..FREQUENCIES.len())] as char
+            } else {
+                '.'
+            };
+            input.push(cell);
+        }
+        input.push('\n');
+    }
+    input
+}
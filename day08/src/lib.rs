@@ -0,0 +1,75 @@
+use common::{Grid, ParseError, Point, Solution};
+use std::collections::HashMap;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod visualise;
+
+pub fn squared_distance(a: &Point, b: &Point) -> i32 {
+    (a.row - b.row).pow(2) + (a.col - b.col).pow(2)
+}
+
+/// The antenna map along with antenna positions grouped by frequency.
+#[derive(Debug)]
+pub struct AntennaMap {
+    pub grid: Grid<char>,
+    pub frequency_groups: HashMap<char, Vec<Point>>,
+}
+
+pub fn parse_input(input: &str) -> Result<AntennaMap, ParseError> {
+    let grid = Grid::parse_chars(input, |c| c.is_ascii_alphanumeric() || c == '.' || c == '#')?;
+
+    // Group antennas by frequency
+    let mut frequency_groups: HashMap<char, Vec<Point>> = HashMap::new();
+    for (point, &ch) in grid.iter() {
+        // Frequencies are letters and digits; '#' marks an antinode in the
+        // puzzle's worked examples, which are pasted in with them drawn
+        if ch != '.' && ch != '#' {
+            frequency_groups.entry(ch).or_default().push(point);
+        }
+    }
+
+    Ok(AntennaMap {
+        grid,
+        frequency_groups,
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = AntennaMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        part1::count_antinodes(map)
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        part2::count_antinodes(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_antinode_markers_are_not_antennas() {
+        let map = parse_input("#..\n.a.\n..a\n").unwrap();
+        assert_eq!(map.frequency_groups.len(), 1);
+        assert_eq!(
+            map.frequency_groups[&'a'],
+            [Point::new(1, 1), Point::new(2, 2)]
+        );
+        assert_eq!(Day08::part1(&map), 1);
+    }
+}
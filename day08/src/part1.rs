@@ -0,0 +1,116 @@
+use crate::{parse_input, squared_distance, AntennaMap};
+use common::Point;
+use std::collections::HashSet;
+
+pub fn solve(input: &str) -> usize {
+    let map = parse_input(input).expect("Failed to parse input");
+    count_antinodes(&map)
+}
+
+pub fn count_antinodes(map: &AntennaMap) -> usize {
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    // Process each frequency group
+    for (freq, antennas) in map.frequency_groups.iter() {
+        log::debug!("Processing frequency '{}'", freq);
+        antinodes.extend(frequency_antinodes(map, antennas));
+    }
+
+    antinodes.len()
+}
+
+/// The antinodes of one frequency's antennas.
+pub fn frequency_antinodes(map: &AntennaMap, antennas: &[Point]) -> HashSet<Point> {
+    let mut antinodes = HashSet::new();
+
+    // Check all pairs of antennas with the same frequency
+    for i in 0..antennas.len() {
+        for j in (i + 1)..antennas.len() {
+            let a1 = &antennas[i];
+            let a2 = &antennas[j];
+
+            // Check each potential point in the grid
+            for p in map.grid.points() {
+                // Calculate squared distances
+                let d1 = squared_distance(&p, a1);
+                let d2 = squared_distance(&p, a2);
+
+                // Check if point satisfies both conditions:
+                // 1. One antenna is exactly twice as far as the other (squared distances in 4:1 ratio)
+                // 2. Point is collinear with both antennas
+                if (d1 == 4 * d2 || d2 == 4 * d1) && are_collinear(a1, a2, &p) {
+                    // Check if point lies between antennas or outside them
+                    if lies_between(a1, a2, &p) {
+                        // Skip points that lie between the antennas
+                        continue;
+                    }
+                    antinodes.insert(p);
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+fn are_collinear(p1: &Point, p2: &Point, p3: &Point) -> bool {
+    // Check if three points are collinear using cross product
+    let dx1 = p2.col - p1.col;
+    let dy1 = p2.row - p1.row;
+    let dx2 = p3.col - p1.col;
+    let dy2 = p3.row - p1.row;
+
+    // If cross product is 0, points are collinear
+    dx1 * dy2 == dx2 * dy1
+}
+
+fn lies_between(p1: &Point, p2: &Point, p3: &Point) -> bool {
+    // Check if p3 lies between p1 and p2
+    // First confirm points are collinear
+    if !are_collinear(p1, p2, p3) {
+        return false;
+    }
+
+    // Check if point lies within the bounding box of the two antennas
+    p3.col >= p1.col.min(p2.col)
+        && p3.col <= p1.col.max(p2.col)
+        && p3.row >= p1.row.min(p2.row)
+        && p3.row <= p1.row.max(p2.row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_case() {
+        let input = "..........\n\
+                     ...#......\n\
+                     ..........\n\
+                     ....a.....\n\
+                     ..........\n\
+                     .....a....\n\
+                     ..........\n\
+                     ......#...\n\
+                     ..........\n\
+                     ..........";
+        assert_eq!(solve(input), 2);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = "............\n\
+                     ........0...\n\
+                     .....0......\n\
+                     .......0....\n\
+                     ....0.......\n\
+                     ......A.....\n\
+                     ............\n\
+                     ............\n\
+                     ........A...\n\
+                     .........A..\n\
+                     ............\n\
+                     ............";
+        assert_eq!(solve(input), 14);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{parse_input, AntennaMap};
+use common::Point;
+use std::collections::HashSet;
+
+pub fn solve(input: &str) -> usize {
+    let map = parse_input(input).expect("Failed to parse input");
+    count_antinodes(&map)
+}
+
+pub fn count_antinodes(map: &AntennaMap) -> usize {
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    // Process each frequency group
+    for (freq, antennas) in map.frequency_groups.iter() {
+        log::debug!("Processing frequency '{}'", freq);
+        antinodes.extend(frequency_antinodes(map, antennas));
+    }
+
+    antinodes.len()
+}
+
+/// The antinodes of one frequency's antennas: every point in line with two
+/// of them, which needs at least two antennas.
+pub fn frequency_antinodes(map: &AntennaMap, antennas: &[Point]) -> HashSet<Point> {
+    let mut antinodes = HashSet::new();
+
+    // Check each point in the grid
+    for p in map.grid.points() {
+        // For each point, check if it's collinear with any pair of antennas
+        for i in 0..antennas.len() {
+            for j in (i + 1)..antennas.len() {
+                if are_collinear(&antennas[i], &antennas[j], &p) {
+                    antinodes.insert(p);
+                    break; // Once we find one collinear pair, we can stop checking this point
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+fn are_collinear(p1: &Point, p2: &Point, p3: &Point) -> bool {
+    // Check if three points are collinear using cross product
+    let dx1 = p2.col - p1.col;
+    let dy1 = p2.row - p1.row;
+    let dx2 = p3.col - p1.col;
+    let dy2 = p3.row - p1.row;
+
+    // If cross product is 0, points are collinear
+    dx1 * dy2 == dx2 * dy1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_t_example() {
+        let input = "T....#....\n\
+                     ...T......\n\
+                     .T....#...\n\
+                     .........#\n\
+                     ..#.......\n\
+                     ..........\n\
+                     ...#......\n\
+                     ..........\n\
+                     ....#.....\n\
+                     ..........";
+        assert_eq!(solve(input), 9);
+    }
+
+    #[test]
+    fn test_full_example() {
+        let input = "............\n\
+                     ........0...\n\
+                     .....0......\n\
+                     .......0....\n\
+                     ....0.......\n\
+                     ......A.....\n\
+                     ............\n\
+                     ............\n\
+                     ........A...\n\
+                     .........A..\n\
+                     ............\n\
+                     ............";
+        assert_eq!(solve(input), 34);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{part1, part2, Day08};
+use common::render::{Colour, Frame, Visualise};
+use common::Point;
+use std::collections::HashSet;
+
+/// One frequency at a time: its antennas, the points on the lines through
+/// them (part 2) and, marked `#`, the antinodes of part 1.
+impl Visualise for Day08 {
+    fn frames(map: Self::Input) -> Box<dyn Iterator<Item = Frame>> {
+        let mut frequencies: Vec<char> = map.frequency_groups.keys().copied().collect();
+        frequencies.sort_unstable();
+
+        let mut frame = Frame::new(&map.grid, |&tile| if tile == '#' { '.' } else { tile })
+            .with_caption(format!(
+                "{} antennas",
+                map.frequency_groups.values().map(Vec::len).sum::<usize>()
+            ));
+        let mut frames = vec![frame.clone()];
+        let mut antinodes: HashSet<Point> = HashSet::new();
+        let mut on_lines: HashSet<Point> = HashSet::new();
+
+        for (i, frequency) in frequencies.into_iter().enumerate() {
+            let colour = Colour::palette(i);
+            let antennas = &map.frequency_groups[&frequency];
+            let lines = part2::frequency_antinodes(&map, antennas);
+            let nodes = part1::frequency_antinodes(&map, antennas);
+
+            frame.overlay(lines.iter().copied(), None, colour);
+            frame.overlay(nodes.iter().copied(), Some('#'), colour);
+            frame.overlay(antennas.iter().copied(), Some(frequency), colour);
+            frame.highlight(antennas.iter().copied(), Colour::GREY);
+
+            antinodes.extend(&nodes);
+            on_lines.extend(&lines);
+            frame.caption = format!(
+                "Frequency '{}': {} antinodes, {} on its lines ({} and {} in total)",
+                frequency,
+                nodes.len(),
+                lines.len(),
+                antinodes.len(),
+                on_lines.len()
+            );
+            frames.push(frame.clone());
+        }
+
+        Box::new(frames.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_frames_add_a_frequency_at_a_time() {
+        let map = Day08::parse("..........\n...#......\n..........\n....a.....\n..........\n.....a....\n..........\n......#...\n..........\n..........\n").unwrap();
+        let frames: Vec<Frame> = Day08::frames(map).collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].caption, "2 antennas");
+        assert_eq!(
+            frames[1].to_string(),
+            "..........\n...#......\n..........\n....a.....\n..........\n.....a....\n..........\n......#...\n..........\n..........\nFrequency 'a': 2 antinodes, 5 on its lines (2 and 5 in total)\n"
+        );
+    }
+}
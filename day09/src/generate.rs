@@ -0,0 +1,18 @@
+use rand::Rng;
+
+/// A disk map of `size` digits, at least one, alternating files of one to
+/// nine blocks with gaps of up to nine.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut input: String = (0..size.max(1))
+        .map(|i| {
+            let digit = if i % 2 == 0 {
+                rng.gen_range(1..=9)
+            } else {
+                rng.gen_range(0..=9)
+            };
+            char::from_digit(digit, 10).unwrap()
+        })
+        .collect();
+    input.push('\n');
+    input
+}
@@ -0,0 +1,64 @@
+use common::{ParseError, Solution};
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+/// Parses the dense disk map into its alternating file and free space sizes.
+pub fn parse_sizes(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut sizes = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        for (j, c) in line.trim_end().chars().enumerate() {
+            let digit = c.to_digit(10).ok_or(ParseError::UnexpectedChar {
+                line: i + 1,
+                column: j + 1,
+                found: c,
+            })?;
+            sizes.push(digit as usize);
+        }
+    }
+    Ok(sizes)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_sizes(input)
+    }
+
+    fn part1(sizes: &Self::Input) -> usize {
+        let blocks = part1::layout_files(sizes);
+        let mut expanded_blocks = part1::expand_to_individual_blocks(&blocks);
+        part1::compact_disk(&mut expanded_blocks);
+        part1::calculate_checksum(&expanded_blocks)
+    }
+
+    fn part2(sizes: &Self::Input) -> usize {
+        let mut blocks = part2::expand_disk_map(sizes);
+        part2::compact_disk(&mut blocks);
+        part2::calculate_checksum(&blocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_digits_are_reported() {
+        assert_eq!(parse_sizes("12345\n").unwrap(), vec![1, 2, 3, 4, 5]);
+
+        let error = parse_sizes("123x5\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 1, column 4: unexpected character 'x'"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::parse_sizes;
+use common::ParseError;
+
+pub fn solve(input: &str) -> usize {
+    let mut blocks = parse_disk_map(input).expect("Failed to parse input");
+    compact_disk(&mut blocks);
+    calculate_checksum(&blocks)
+}
+
+pub fn parse_disk_map(input: &str) -> Result<Vec<Option<usize>>, ParseError> {
+    Ok(expand_disk_map(&parse_sizes(input)?))
+}
+
+pub fn expand_disk_map(numbers: &[usize]) -> Vec<Option<usize>> {
+    let mut result = Vec::new();
+    let mut file_id = 0;
+
+    for (i, &size) in numbers.iter().enumerate() {
+        if i % 2 == 0 {
+            // File block
+            for _ in 0..size {
+                result.push(Some(file_id));
+            }
+            if size > 0 {
+                file_id += 1;
+            }
+        } else {
+            // Space block
+            for _ in 0..size {
+                result.push(None);
+            }
+        }
+    }
+
+    result
+}
+
+fn get_files_info(blocks: &[Option<usize>]) -> Vec<(usize, usize, usize)> {
+    let mut files = Vec::new();
+    let mut current_id = None;
+    let mut start_pos = 0;
+    let mut size = 0;
+
+    for (i, &block) in blocks.iter().enumerate() {
+        match (current_id, block) {
+            (None, Some(id)) => {
+                current_id = Some(id);
+                start_pos = i;
+                size = 1;
+            }
+            (Some(curr_id), Some(id)) if curr_id == id => {
+                size += 1;
+            }
+            (Some(curr_id), _) => {
+                files.push((curr_id, start_pos, size));
+                current_id = block;
+                start_pos = i;
+                size = if block.is_some() { 1 } else { 0 };
+            }
+            (None, None) => {}
+        }
+    }
+    if let Some(id) = current_id {
+        files.push((id, start_pos, size));
+    }
+
+    files
+}
+
+fn find_free_space(
+    blocks: &[Option<usize>],
+    start_limit: usize,
+    size_needed: usize,
+) -> Option<usize> {
+    let mut current_free = 0;
+    let mut free_start = 0;
+
+    for (pos, &block) in blocks.iter().enumerate() {
+        if pos >= start_limit {
+            break;
+        }
+
+        if block.is_none() {
+            if current_free == 0 {
+                free_start = pos;
+            }
+            current_free += 1;
+            if current_free >= size_needed {
+                return Some(free_start);
+            }
+        } else {
+            current_free = 0;
+        }
+    }
+    None
+}
+
+pub fn compact_disk(blocks: &mut [Option<usize>]) {
+    let files = get_files_info(blocks);
+
+    // Process files in order of decreasing ID
+    for &(file_id, start_pos, file_size) in files.iter().rev() {
+        // Find leftmost suitable free space
+        if let Some(new_pos) = find_free_space(blocks, start_pos, file_size) {
+            // Clear old position
+            blocks[start_pos..start_pos + file_size].fill(None);
+            // Place in new position
+            blocks[new_pos..new_pos + file_size].fill(Some(file_id));
+        }
+    }
+}
+
+pub fn calculate_checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, &file_id)| file_id.map(|id| pos * id))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "2333133121414131402";
+        let mut blocks = parse_disk_map(input).unwrap();
+        compact_disk(&mut blocks);
+        let checksum = calculate_checksum(&blocks);
+        assert_eq!(checksum, 2858);
+    }
+
+    #[test]
+    fn test_visualization() {
+        let input = "2333133121414131402";
+        let mut blocks = parse_disk_map(input).unwrap();
+        println!("Initial:");
+        for block in &blocks {
+            match block {
+                Some(id) => print!("{}", id),
+                None => print!("."),
+            }
+        }
+        println!();
+
+        compact_disk(&mut blocks);
+        println!("After compaction:");
+        for block in &blocks {
+            match block {
+                Some(id) => print!("{}", id),
+                None => print!("."),
+            }
+        }
+        println!();
+    }
+}
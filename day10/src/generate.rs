@@ -0,0 +1,15 @@
+use rand::Rng;
+
+/// A `size` by `size` topographic map. Heights follow gentle slopes with a
+/// little noise, so hiking trails are common.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for row in 0..size {
+        for column in 0..size {
+            let height = (row + column + rng.gen_range(0..2)) % 10;
+            input.push(char::from_digit(height as u32, 10).unwrap());
+        }
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,77 @@
+use common::mode::is_strict;
+use common::{Grid, ParseError, Solution};
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod visualise;
+
+/// Height given to the impassable `.` tiles used in the puzzle examples.
+/// They are kept as tiles, rather than skipped, so that the digits after
+/// them stay in their columns.
+pub const IMPASSABLE: u32 = u32::MAX;
+
+/// Parses the topographic map. Anything other than a digit is impassable,
+/// though strict parsing only allows `.` for that.
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    let strict = is_strict();
+    Grid::parse_with(input, |c| match c.to_digit(10) {
+        Some(height) => Some(height),
+        None if c == '.' || !strict => Some(IMPASSABLE),
+        None => None,
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        part1::solve(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        part2::solve(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::mode::{with_parse_mode, ParseMode};
+
+    #[test]
+    fn test_non_digits_depend_on_mode() {
+        let grid = with_parse_mode(ParseMode::Lenient, || parse_input("01\nx.\n")).unwrap();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [IMPASSABLE; 2]);
+
+        let error = with_parse_mode(ParseMode::Strict, || parse_input("01\nx.\n")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 1: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn test_impassable_tiles_keep_columns() {
+        // Skipping the '.' would move the 1 under the 0
+        let grid = parse_input(
+            "0.
+.1
+",
+        )
+        .unwrap();
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [IMPASSABLE, 1]);
+        assert_eq!(grid[common::Point::new(1, 1)], 1);
+    }
+}
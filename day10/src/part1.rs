@@ -0,0 +1,79 @@
+use common::{Grid, Point};
+use std::collections::{HashSet, VecDeque};
+
+pub fn find_trailheads(grid: &Grid<u32>) -> Vec<Point> {
+    grid.positions(|&height| height == 0).collect()
+}
+
+fn calculate_trailhead_score(start: Point, grid: &Grid<u32>) -> usize {
+    trail_points(start, grid)
+        .into_iter()
+        .filter(|&point| grid[point] == 9)
+        .count()
+}
+
+/// Every point on a hiking trail from `start`, including the 9s it reaches.
+pub fn trail_points(start: Point, grid: &Grid<u32>) -> HashSet<Point> {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    // Store the current height along with each point
+    queue.push_back((start, 0));
+    visited.insert(start);
+
+    while let Some((current, height)) = queue.pop_front() {
+        // Check all neighbors
+        for next in grid.neighbours4(current) {
+            let next_height = grid[next];
+
+            // Only continue if this forms a valid hiking trail
+            // (height increases by exactly 1)
+            if next_height == height + 1 && !visited.contains(&next) {
+                queue.push_back((next, next_height));
+                visited.insert(next);
+            }
+        }
+    }
+
+    visited
+}
+
+pub fn solve(grid: &Grid<u32>) -> usize {
+    let trailheads = find_trailheads(grid);
+    trailheads
+        .iter()
+        .map(|&start| calculate_trailhead_score(start, grid))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_example() {
+        let input = "89010123\n\
+                     78121874\n\
+                     87430965\n\
+                     96549874\n\
+                     45678903\n\
+                     32019012\n\
+                     01329801\n\
+                     10456732";
+
+        let grid = parse_input(input).unwrap();
+        assert_eq!(solve(&grid), 36);
+    }
+
+    #[test]
+    fn test_small_example() {
+        let input = "0123\n\
+                     1234\n\
+                     8765\n\
+                     9876";
+
+        let grid = parse_input(input).unwrap();
+        assert_eq!(solve(&grid), 1);
+    }
+}
@@ -0,0 +1,107 @@
+use common::{Grid, Point};
+use std::collections::HashSet;
+
+pub fn find_trailheads(grid: &Grid<u32>) -> Vec<Point> {
+    grid.positions(|&height| height == 0).collect()
+}
+
+fn get_valid_next_steps(
+    point: Point,
+    current_height: u32,
+    grid: &Grid<u32>,
+    visited: &HashSet<Point>,
+) -> Vec<Point> {
+    grid.neighbours4(point)
+        .filter(|p| !visited.contains(p) && grid[*p] == current_height + 1)
+        .collect()
+}
+
+fn count_distinct_trails(current: Point, grid: &Grid<u32>, visited: &mut HashSet<Point>) -> usize {
+    let current_height = grid[current];
+
+    // If we've reached height 9, we've found a valid trail
+    if current_height == 9 {
+        return 1;
+    }
+
+    // Mark current position as visited
+    visited.insert(current);
+
+    // Get all valid next steps
+    let next_steps = get_valid_next_steps(current, current_height, grid, visited);
+
+    // Count trails from each valid next step
+    let total_trails: usize = next_steps
+        .iter()
+        .map(|&next| {
+            let trails = count_distinct_trails(next, grid, visited);
+            visited.remove(&next); // Backtrack
+            trails
+        })
+        .sum();
+
+    total_trails
+}
+
+pub fn calculate_trailhead_rating(start: Point, grid: &Grid<u32>) -> usize {
+    let mut visited = HashSet::new();
+    count_distinct_trails(start, grid, &mut visited)
+}
+
+pub fn solve(grid: &Grid<u32>) -> usize {
+    let trailheads = find_trailheads(grid);
+    trailheads
+        .iter()
+        .map(|&start| calculate_trailhead_rating(start, grid))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn test_single_trailhead_three_paths() {
+        let input = "\
+            .....0.\n\
+            ..4321.\n\
+            ..5..2.\n\
+            ..6543.\n\
+            ..7..4.\n\
+            ..8765.\n\
+            ..9....";
+        let grid = parse_input(input).unwrap();
+        let trailheads = find_trailheads(&grid);
+        assert_eq!(calculate_trailhead_rating(trailheads[0], &grid), 3);
+    }
+
+    #[test]
+    fn test_larger_example() {
+        let input = "\
+            89010123\n\
+            78121874\n\
+            87430965\n\
+            96549874\n\
+            45678903\n\
+            32019012\n\
+            01329801\n\
+            10456732";
+        let grid = parse_input(input).unwrap();
+        assert_eq!(solve(&grid), 81);
+    }
+
+    #[test]
+    fn test_complex_example() {
+        let input = "\
+            012345\n\
+            123456\n\
+            234567\n\
+            345678\n\
+            4.6789\n\
+            56789.";
+        let grid = parse_input(input).unwrap();
+        let trailheads = find_trailheads(&grid);
+        assert_eq!(calculate_trailhead_rating(trailheads[0], &grid), 227);
+    }
+}
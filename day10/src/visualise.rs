@@ -0,0 +1,65 @@
+use crate::{part1, part2, Day10, IMPASSABLE};
+use common::render::{Colour, Frame, Visualise};
+use common::Point;
+use std::collections::HashSet;
+
+/// One trailhead at a time: the trails climbing from it and the 9s they
+/// reach, with earlier trailheads' trails left in grey.
+impl Visualise for Day10 {
+    fn frames(grid: Self::Input) -> Box<dyn Iterator<Item = Frame>> {
+        let mut frame = Frame::new(&grid, |&height| match height {
+            IMPASSABLE => '.',
+            height => char::from_digit(height % 10, 10).unwrap(),
+        });
+        let trailheads = part1::find_trailheads(&grid);
+        let mut frames = vec![frame
+            .clone()
+            .with_caption(format!("{} trailheads", trailheads.len()))];
+
+        let mut previous: HashSet<Point> = HashSet::new();
+        let (mut total_score, mut total_rating) = (0, 0);
+        for start in trailheads {
+            frame.overlay(previous.drain(), None, Colour::GREY);
+            let trails = part1::trail_points(start, &grid);
+            let peaks: Vec<Point> = trails.iter().copied().filter(|&p| grid[p] == 9).collect();
+            let rating = part2::calculate_trailhead_rating(start, &grid);
+            total_score += peaks.len();
+            total_rating += rating;
+
+            frame.overlay(trails.iter().copied(), None, Colour::CYAN);
+            frame.overlay(peaks.iter().copied(), None, Colour::RED);
+            frame.overlay([start], None, Colour::GREEN);
+            frame.caption = format!(
+                "Trailhead at row {}, column {}: score {}, rating {} (totals {} and {})",
+                start.row,
+                start.col,
+                peaks.len(),
+                rating,
+                total_score,
+                total_rating
+            );
+            frames.push(frame.clone());
+            previous = trails;
+        }
+
+        Box::new(frames.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_frames_end_with_the_totals() {
+        let grid = Day10::parse("0123\n1234\n8765\n9876\n").unwrap();
+        let frames: Vec<Frame> = Day10::frames(grid).collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            frames[1].caption,
+            "Trailhead at row 0, column 0: score 1, rating 16 (totals 1 and 16)"
+        );
+        assert_eq!(frames[1].cells[Point::new(3, 0)].fg, Some(Colour::RED));
+    }
+}
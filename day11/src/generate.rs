@@ -0,0 +1,10 @@
+use rand::Rng;
+
+/// A line of `size` stones, at least one, engraved with numbers below a
+/// million.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| rng.gen_range(0..1_000_000u64).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
@@ -0,0 +1,55 @@
+use common::error::parse_numbers;
+use common::mode::content_lines;
+use common::{ParseError, Solution};
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+/// Parses the engraved numbers on the stones, which may span several lines.
+pub fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut stones = Vec::new();
+    for line in content_lines(input) {
+        let (number, line) = line?;
+        stones.extend(parse_numbers::<u64>(number, line)?);
+    }
+    Ok(stones)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(stones: &Self::Input) -> usize {
+        part1::count_stones(stones.iter().map(|stone| stone.to_string()).collect())
+    }
+
+    fn part2(stones: &Self::Input) -> i64 {
+        part2::count_stones(stones)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("125 17\n").unwrap(), vec![125, 17]);
+
+        let error = parse_input("125 -17\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 1, column 5: invalid number \"-17\""
+        );
+    }
+}
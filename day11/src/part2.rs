@@ -0,0 +1,93 @@
+use crate::parse_input;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Stone {
+    /// Wider than the input so that multiplying the largest u64 stones fits.
+    val: u128,
+    amount: i64,
+}
+
+pub fn solve(input: &str) -> i64 {
+    let initial_stones = parse_input(input).expect("Failed to parse input");
+    count_stones(&initial_stones)
+}
+
+pub fn count_stones(initial_stones: &[u64]) -> i64 {
+    count_stones_after(initial_stones, 75)
+}
+
+/// Counts the stones after `blinks` blinks, tracking how many of each
+/// engraving there are rather than every stone.
+pub fn count_stones_after(initial_stones: &[u64], blinks: usize) -> i64 {
+    let mut stones: Vec<Stone> = initial_stones
+        .iter()
+        .map(|&val| Stone {
+            val: u128::from(val),
+            amount: 1,
+        })
+        .collect();
+
+    for step in 0..blinks {
+        let mut new_stones = HashMap::new();
+
+        for stone in stones {
+            if stone.val == 0 {
+                *new_stones.entry(1).or_insert(0) += stone.amount;
+            } else if stone.val.to_string().len() % 2 == 0 {
+                let s = stone.val.to_string();
+                let mid = s.len() / 2;
+                let first: u128 = s[..mid].parse().unwrap_or(0);
+                let second: u128 = s[mid..].parse().unwrap_or(0);
+                *new_stones.entry(first).or_insert(0) += stone.amount;
+                *new_stones.entry(second).or_insert(0) += stone.amount;
+            } else {
+                *new_stones.entry(stone.val * 2024).or_insert(0) += stone.amount;
+            }
+        }
+
+        stones = new_stones
+            .into_iter()
+            .map(|(val, amount)| Stone { val, amount })
+            .collect();
+
+        log::debug!(
+            "Step {}: {} stones",
+            step + 1,
+            stones.iter().map(|s| s.amount).sum::<i64>()
+        );
+    }
+
+    stones.iter().map(|s| s.amount).sum::<i64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_largest_stones() {
+        let stone = 9_999_999_999_999_999_999;
+        // As many as part 1 simulates
+        assert_eq!(count_stones_after(&[stone], 10), 31);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn prop_counting_matches_simulation(
+            stones in vec(0..100_000u64, 1..4),
+            blinks in 0..15usize,
+        ) {
+            let simulated = part1::count_stones_after(
+                stones.iter().map(|stone| stone.to_string()).collect(),
+                blinks,
+            );
+            prop_assert_eq!(count_stones_after(&stones, blinks), simulated as i64);
+        }
+    }
+}
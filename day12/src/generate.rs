@@ -0,0 +1,26 @@
+use rand::Rng;
+
+/// A `size` by `size` garden of plants A to E. Each plot usually matches a
+/// neighbour above or to the left, so regions grow to a variety of shapes.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut rows: Vec<Vec<char>> = Vec::with_capacity(size);
+    for row in 0..size {
+        let mut plots = Vec::with_capacity(size);
+        for column in 0..size {
+            let plant = match rng.gen_range(0..10) {
+                0..=3 if row > 0 => rows[row - 1][column],
+                4..=7 if column > 0 => plots[column - 1],
+                _ => (b'A' + rng.gen_range(0..5)) as char,
+            };
+            plots.push(plant);
+        }
+        rows.push(plots);
+    }
+
+    let mut input = String::new();
+    for plots in rows {
+        input.extend(plots);
+        input.push('\n');
+    }
+    input
+}
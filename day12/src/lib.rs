@@ -0,0 +1,52 @@
+use common::{Grid, ParseError, Solution};
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod visualise;
+
+/// Parses the garden plots, which are labelled with capital letters.
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_chars(input, |c| c.is_ascii_uppercase())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> i32 {
+        part1::total_price(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        part2::total_price(part2::Map::from_grid(grid.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let grid = Day12::parse("AAAA\nBBCD\nBBCC\nEEEC\n").unwrap();
+        assert_eq!(Day12::part1(&grid), 140);
+        assert_eq!(Day12::part2(&grid), 80);
+    }
+
+    #[test]
+    fn test_enclosed_regions() {
+        let grid = Day12::parse("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n").unwrap();
+        assert_eq!(Day12::part1(&grid), 772);
+        assert_eq!(Day12::part2(&grid), 436);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::parse_input;
+use common::{Grid, Point};
+use std::collections::{HashSet, VecDeque};
+
+fn find_region(grid: &Grid<char>, start: Point, visited: &mut HashSet<Point>) -> (i32, i32) {
+    // Returns (area, perimeter)
+    let region = region_points(grid, start);
+    visited.extend(&region);
+    (region.len() as i32, perimeter(grid, &region))
+}
+
+/// The plots of the region containing `start`.
+pub fn region_points(grid: &Grid<char>, start: Point) -> HashSet<Point> {
+    let target_char = grid[start];
+
+    let mut queue = VecDeque::new();
+    let mut region = HashSet::new();
+    queue.push_back(start);
+    region.insert(start);
+
+    while let Some(current) = queue.pop_front() {
+        for neighbor in grid.neighbours4(current) {
+            if grid[neighbor] == target_char && region.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    region
+}
+
+/// The length of fence needed around `region`.
+pub fn perimeter(grid: &Grid<char>, region: &HashSet<Point>) -> i32 {
+    let Some(&first) = region.iter().next() else {
+        return 0;
+    };
+    let target_char = grid[first];
+    let mut perimeter = 0;
+
+    for point in region {
+        // Sides facing the edge of the map or a different crop need fencing
+        perimeter += 4 - grid
+            .neighbours4(*point)
+            .filter(|&neighbor| grid[neighbor] == target_char)
+            .count() as i32;
+    }
+
+    perimeter
+}
+
+pub fn solve(input: &str) -> i32 {
+    let grid = parse_input(input).expect("Failed to parse input");
+    total_price(&grid)
+}
+
+pub fn total_price(grid: &Grid<char>) -> i32 {
+    let mut visited = HashSet::new();
+    let mut total_price = 0;
+
+    // Process each cell in the grid
+    for point in grid.points() {
+        if !visited.contains(&point) {
+            let (area, perimeter) = find_region(grid, point, &mut visited);
+            let price = area * perimeter;
+            total_price += price;
+        }
+    }
+
+    total_price
+}
@@ -0,0 +1,145 @@
+use crate::parse_input;
+use common::{Direction, Grid, Point};
+
+#[derive(Clone)]
+pub struct Map {
+    data: Grid<char>,
+    visited: Grid<bool>,
+}
+
+impl Map {
+    fn from_text(text: &str) -> Map {
+        let data = parse_input(text).expect("Failed to parse input");
+        Map::from_grid(data)
+    }
+
+    pub fn from_grid(data: Grid<char>) -> Map {
+        let visited = Grid::new(data.width(), data.height(), false);
+        Map { data, visited }
+    }
+}
+
+pub struct Region {
+    coordinates: Vec<Point>,
+}
+
+impl Region {
+    pub fn new(coordinates: Vec<Point>) -> Region {
+        Region { coordinates }
+    }
+
+    /// A region has as many sides as corners.
+    pub fn calculate_sides(&self) -> usize {
+        self.corners().len()
+    }
+
+    /// The corners of the region's outline, as a plot on the map and which
+    /// of its corners it is.
+    pub fn corners(&self) -> Vec<(Point, Direction)> {
+        let map = self.to_padded_bool_map();
+        let offset = self.get_range().0 - Point::new(1, 1);
+
+        let mut corners = Vec::new();
+        for pos in map.positions(|&inside| inside) {
+            for corner in Direction::DIAGONAL {
+                if Self::is_corner(&map, pos, corner) {
+                    corners.push((pos + offset, corner));
+                }
+            }
+        }
+        corners
+    }
+
+    fn is_corner(map: &Grid<bool>, pos: Point, corner: Direction) -> bool {
+        let adjacents = match corner {
+            Direction::UpLeft => (Direction::Up, Direction::Left),
+            Direction::DownLeft => (Direction::Down, Direction::Left),
+            Direction::UpRight => (Direction::Up, Direction::Right),
+            Direction::DownRight => (Direction::Down, Direction::Right),
+            _ => panic!("Not a corner!"),
+        };
+        let c = map[pos.step(corner)];
+        let a0 = map[pos.step(adjacents.0)];
+        let a1 = map[pos.step(adjacents.1)];
+        (!c && (a0 == a1)) || (c && !a0 && !a1)
+    }
+
+    fn to_padded_bool_map(&self) -> Grid<bool> {
+        let (min, max) = self.get_range();
+        let width = (max.col - min.col) as usize;
+        let height = (max.row - min.row) as usize;
+        let mut map = Grid::new(width + 3, height + 3, false);
+        // Shift so the region's top left cell lands at (1, 1)
+        let offset = min - Point::new(1, 1);
+        for &coord in &self.coordinates {
+            map[coord - offset] = true;
+        }
+        map
+    }
+
+    fn get_range(&self) -> (Point, Point) {
+        let mut min = Point::new(i32::MAX, i32::MAX);
+        let mut max = Point::new(i32::MIN, i32::MIN);
+        for coord in &self.coordinates {
+            min.row = min.row.min(coord.row);
+            min.col = min.col.min(coord.col);
+            max.row = max.row.max(coord.row);
+            max.col = max.col.max(coord.col);
+        }
+        (min, max)
+    }
+}
+
+fn get_crop_coordinates(map: &mut Map, pos: Point) -> Vec<Point> {
+    map.visited[pos] = true;
+    let mut coordinates = vec![pos];
+
+    let crop_type = map.data[pos];
+    for dir in Direction::ORTHOGONAL {
+        let next = pos.step(dir);
+
+        if map.data.get(next) == Some(&crop_type) && !map.visited[next] {
+            coordinates.extend(get_crop_coordinates(map, next));
+        }
+    }
+    coordinates
+}
+
+pub fn solve(text: &str) -> usize {
+    total_price(Map::from_text(text))
+}
+
+pub fn total_price(mut map: Map) -> usize {
+    let mut total_cost = 0;
+    for pos in map.data.points() {
+        if map.visited[pos] {
+            continue;
+        }
+        let region = Region::new(get_crop_coordinates(&mut map, pos));
+        total_cost += region.coordinates.len() * region.calculate_sides();
+    }
+    total_cost
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corners_are_on_the_map() {
+        // An L of plots away from the top left, so the padding offset matters
+        let region = Region::new(vec![Point::new(2, 5), Point::new(3, 5), Point::new(3, 6)]);
+        assert_eq!(
+            region.corners(),
+            vec![
+                (Point::new(2, 5), Direction::UpRight),
+                (Point::new(2, 5), Direction::UpLeft),
+                (Point::new(3, 5), Direction::UpRight),
+                (Point::new(3, 5), Direction::DownLeft),
+                (Point::new(3, 6), Direction::UpRight),
+                (Point::new(3, 6), Direction::DownRight),
+            ]
+        );
+        assert_eq!(region.calculate_sides(), 6);
+    }
+}
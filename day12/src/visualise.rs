@@ -0,0 +1,68 @@
+use crate::part2::Region;
+use crate::{part1, Day12};
+use common::render::{Colour, Frame, Visualise};
+use common::Point;
+use std::collections::HashSet;
+
+/// One region at a time, filled with a colour for its plant, with the
+/// corners counted as its sides marked.
+impl Visualise for Day12 {
+    fn frames(grid: Self::Input) -> Box<dyn Iterator<Item = Frame>> {
+        let mut frame = Frame::new(&grid, |&plant| plant);
+        let mut frames = Vec::new();
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut total_price = 0;
+
+        for start in grid.points() {
+            if visited.contains(&start) {
+                continue;
+            }
+            let plant = grid[start];
+            let region = part1::region_points(&grid, start);
+            let (area, perimeter) = (region.len() as i32, part1::perimeter(&grid, &region));
+            total_price += area * perimeter;
+            visited.extend(&region);
+
+            frame.overlay(region.iter().copied(), None, Colour::WHITE);
+            frame.highlight(region.iter().copied(), Colour::palette(plant as usize));
+            let corners = Region::new(region.into_iter().collect()).corners();
+            for &(point, corner) in &corners {
+                frame.mark_corner(point, corner, Colour::WHITE);
+            }
+            frame.caption = format!(
+                "Region of {} plants: area {}, perimeter {}, sides {}, price {} (total {})",
+                plant,
+                area,
+                perimeter,
+                corners.len(),
+                area * perimeter,
+                total_price
+            );
+            frames.push(frame.clone());
+        }
+
+        Box::new(frames.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_a_frame_per_region() {
+        let grid = Day12::parse("AAAA\nBBCD\nBBCC\nEEEC\n").unwrap();
+        let frames: Vec<Frame> = Day12::frames(grid).collect();
+        assert_eq!(frames.len(), 5);
+        assert_eq!(
+            frames[4].caption,
+            "Region of E plants: area 3, perimeter 8, sides 4, price 24 (total 140)"
+        );
+        assert_eq!(frames[4].corners.len(), 24);
+        assert_eq!(
+            frames[4].cells[Point::new(1, 0)].bg,
+            Some(Colour::palette('B' as usize))
+        );
+    }
+}
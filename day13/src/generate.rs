@@ -0,0 +1,28 @@
+use rand::Rng;
+use std::fmt::Write;
+
+/// `size` claw machines. Roughly half have prizes that can be won within a
+/// hundred presses of each button.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for i in 0..size {
+        if i > 0 {
+            input.push('\n');
+        }
+        let a: (u64, u64) = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let b: (u64, u64) = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let prize = if rng.gen_bool(0.5) {
+            let (presses_a, presses_b) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+            (
+                presses_a * a.0 + presses_b * b.0,
+                presses_a * a.1 + presses_b * b.1,
+            )
+        } else {
+            (rng.gen_range(1000..20_000), rng.gen_range(1000..20_000))
+        };
+        writeln!(input, "Button A: X+{}, Y+{}", a.0, a.1).unwrap();
+        writeln!(input, "Button B: X+{}, Y+{}", b.0, b.1).unwrap();
+        writeln!(input, "Prize: X={}, Y={}", prize.0, prize.1).unwrap();
+    }
+    input
+}
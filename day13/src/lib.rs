@@ -0,0 +1,140 @@
+use common::error::parse_number;
+use common::{ParseError, Solution};
+use regex::Regex;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct ClawMachine {
+    pub button_a: (i64, i64), // (X, Y) movement for button A
+    pub button_b: (i64, i64), // (X, Y) movement for button B
+    pub prize: (i64, i64),    // (X, Y) position of prize
+}
+
+/// The three lines describing each machine, with what they should look like.
+const MACHINE_LINES: [(&str, &str); 3] = [
+    (r"^Button A: X\+(\d+), Y\+(\d+)$", "Button A: X+<n>, Y+<n>"),
+    (r"^Button B: X\+(\d+), Y\+(\d+)$", "Button B: X+<n>, Y+<n>"),
+    (r"^Prize: X=(\d+), Y=(\d+)$", "Prize: X=<n>, Y=<n>"),
+];
+
+pub fn parse_input(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    let patterns: Vec<(Regex, &'static str)> = MACHINE_LINES
+        .iter()
+        .map(|&(pattern, expected)| (Regex::new(pattern).unwrap(), expected))
+        .collect();
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty());
+    let mut machines = Vec::new();
+
+    while let Some(first) = lines.next() {
+        let mut values = [(0, 0); 3];
+
+        for (i, (value, (re, expected))) in values.iter_mut().zip(&patterns).enumerate() {
+            let line = if i == 0 { Some(first) } else { lines.next() };
+            let (line_number, text) = line.ok_or_else(|| {
+                ParseError::Missing(format!(
+                    "{:?} line of the claw machine starting on line {}",
+                    expected, first.0
+                ))
+            })?;
+            let captures = re.captures(text).ok_or(ParseError::Malformed {
+                line: line_number,
+                column: 1,
+                text: text.to_string(),
+                expected,
+            })?;
+            *value = (
+                parse_number(line_number, text, &text[captures.get(1).unwrap().range()])?,
+                parse_number(line_number, text, &text[captures.get(2).unwrap().range()])?,
+            );
+        }
+
+        let [button_a, button_b, prize] = values;
+        machines.push(ClawMachine {
+            button_a,
+            button_b,
+            prize,
+        });
+    }
+
+    Ok(machines)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<ClawMachine>;
+    type Answer1 = i64;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Input) -> i64 {
+        machines.iter().filter_map(part1::tokens_to_win).sum()
+    }
+
+    fn part2(machines: &Self::Input) -> i128 {
+        part2::total_tokens(machines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn test_example() {
+        let machines = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(machines.len(), 4);
+        assert_eq!(Day13::part1(&machines), 480);
+        assert_eq!(Day13::part2(&machines), 875318608908);
+    }
+
+    #[test]
+    fn test_bad_machines_are_reported() {
+        let error = parse_input("Button A: X+94, Y+34\nButton B: X-22, Y+67\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 1: expected Button B: X+<n>, Y+<n>, found \"Button B: X-22, Y+67\""
+        );
+
+        let error = parse_input("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Missing \"Prize: X=<n>, Y=<n>\" line of the claw machine starting on line 1"
+        );
+
+        let error = parse_input(&EXAMPLE.replace("12748", "99999999999999999999")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 7, column 10: invalid number \"99999999999999999999\""
+        );
+    }
+}
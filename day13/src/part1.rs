@@ -0,0 +1,50 @@
+use crate::{parse_input, ClawMachine};
+
+/// Where `presses` of two buttons moving `moves` along one axis end up. In
+/// `i128`, as a hundred presses of extreme movements do not fit an `i64`.
+fn reached(presses: (i64, i64), moves: (i64, i64)) -> i128 {
+    i128::from(presses.0) * i128::from(moves.0) + i128::from(presses.1) * i128::from(moves.1)
+}
+
+// Returns Some(tokens) if prize is winnable, None if impossible
+pub fn tokens_to_win(machine: &ClawMachine) -> Option<i64> {
+    // Try all combinations of button presses up to 100 each
+    for a in 0..=100 {
+        for b in 0..=100 {
+            let x = reached((a, b), (machine.button_a.0, machine.button_b.0));
+            let y = reached((a, b), (machine.button_a.1, machine.button_b.1));
+
+            if x == i128::from(machine.prize.0) && y == i128::from(machine.prize.1) {
+                // Calculate total tokens: 3 per A press, 1 per B press
+                return Some(3 * a + b);
+            }
+        }
+    }
+    None
+}
+
+pub fn solve(input: &str) -> i64 {
+    let machines = parse_input(input).expect("Failed to parse input");
+
+    // Calculate total tokens needed for all winnable prizes
+    machines.iter().filter_map(tokens_to_win).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extreme_movements() {
+        assert_eq!(
+            reached((100, 100), (i64::MAX, i64::MAX)),
+            200 * i128::from(i64::MAX)
+        );
+        let machine = ClawMachine {
+            button_a: (i64::MAX, 1),
+            button_b: (1, 1),
+            prize: (5, 5),
+        };
+        assert_eq!(tokens_to_win(&machine), Some(5));
+    }
+}
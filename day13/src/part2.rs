@@ -0,0 +1,121 @@
+use crate::{parse_input, ClawMachine};
+
+// Chinese Remainder Theorem approach to solve the system
+fn solve_system(
+    a1: i128,
+    b1: i128,
+    c1: i128,
+    a2: i128,
+    b2: i128,
+    c2: i128,
+) -> Option<(i128, i128)> {
+    // Solve a1*x + b1*y = c1 and a2*x + b2*y = c2
+
+    // Calculate determinant
+    let det = a1 * b2 - a2 * b1;
+    if det == 0 {
+        return None;
+    }
+
+    // Use Cramer's rule to solve the system
+    let x = (c1 * b2 - c2 * b1) / det;
+    let y = (a1 * c2 - a2 * c1) / det;
+
+    // Check if the solution consists of integers
+    if x * det != (c1 * b2 - c2 * b1) || y * det != (a1 * c2 - a2 * c1) {
+        return None;
+    }
+
+    // Check if solution is non-negative
+    if x >= 0 && y >= 0 {
+        Some((x, y))
+    } else {
+        None
+    }
+}
+
+/// How much further away every prize really is in part 2.
+pub const PRIZE_OFFSET: i128 = 10_000_000_000_000;
+
+pub fn tokens_to_win(machine: &ClawMachine) -> Option<i128> {
+    presses(machine, PRIZE_OFFSET).map(|(a, b)| 3 * a + b)
+}
+
+/// The presses of buttons A and B that win the prize once it has been moved
+/// `offset` further along both axes.
+pub fn presses(machine: &ClawMachine, offset: i128) -> Option<(i128, i128)> {
+    let target_x = i128::from(machine.prize.0) + offset;
+    let target_y = i128::from(machine.prize.1) + offset;
+
+    // Solve the system of equations:
+    // a*button_a.0 + b*button_b.0 = target_x
+    // a*button_a.1 + b*button_b.1 = target_y
+    solve_system(
+        i128::from(machine.button_a.0),
+        i128::from(machine.button_b.0),
+        target_x,
+        i128::from(machine.button_a.1),
+        i128::from(machine.button_b.1),
+        target_y,
+    )
+}
+
+pub fn total_tokens(machines: &[ClawMachine]) -> i128 {
+    let mut total_tokens = 0;
+    let mut solvable_count = 0;
+
+    for (i, machine) in machines.iter().enumerate() {
+        if let Some(tokens) = tokens_to_win(machine) {
+            log::debug!("Machine {} is solvable with {} tokens", i + 1, tokens);
+            total_tokens += tokens;
+            solvable_count += 1;
+        }
+    }
+
+    log::info!("Solvable machines: {}", solvable_count);
+    total_tokens
+}
+
+pub fn solve(input: &str) -> i128 {
+    let machines = parse_input(input).expect("Failed to parse input");
+    total_tokens(&machines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1;
+    use proptest::prelude::*;
+
+    /// Machines whose buttons are not parallel, with a prize that is
+    /// sometimes reachable within part 1's 100 presses of each button.
+    fn machines() -> impl Strategy<Value = ClawMachine> {
+        (
+            (1..100i64, 1..100i64),
+            (1..100i64, 1..100i64),
+            (0..=120i64, 0..=120i64),
+            prop::option::of((0..20_000i64, 0..20_000i64)),
+        )
+            .prop_filter("buttons must not be parallel", |(a, b, _, _)| {
+                a.0 * b.1 != a.1 * b.0
+            })
+            .prop_map(|(button_a, button_b, (a, b), arbitrary)| ClawMachine {
+                button_a,
+                button_b,
+                prize: arbitrary.unwrap_or((
+                    a * button_a.0 + b * button_b.0,
+                    a * button_a.1 + b * button_b.1,
+                )),
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_zero_offset_matches_part1(machine in machines()) {
+            let within_part1_limit = presses(&machine, 0)
+                .filter(|&(a, b)| a <= 100 && b <= 100)
+                .map(|(a, b)| (3 * a + b) as i64);
+            prop_assert_eq!(part1::tokens_to_win(&machine), within_part1_limit);
+        }
+    }
+}
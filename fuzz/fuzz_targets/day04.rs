@@ -0,0 +1,6 @@
+#![no_main]
+
+use aoc_fuzz::parse_and_solve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| parse_and_solve::<day04::Day04>(input));
@@ -0,0 +1,25 @@
+//! Shared driver of the fuzz targets in `fuzz_targets/`, one per day. Run one
+//! with `cargo +nightly fuzz run day07` from this directory. Nothing is
+//! downloaded: the fuzzer starts from an empty corpus, or from whatever is
+//! saved under `corpus/<target>`.
+
+use common::mode::prepare;
+use common::Solution;
+
+/// Longer inputs are skipped so that the slower days keep the fuzzer fast.
+pub const MAX_INPUT_LEN: usize = 2048;
+
+/// Parses `input` as `S`'s puzzle input and, if it is valid, solves both
+/// parts. Invalid input must be reported as an error and never panic.
+pub fn parse_and_solve<S: Solution>(input: &str) {
+    if input.len() > MAX_INPUT_LEN {
+        return;
+    }
+    let Ok(prepared) = prepare(input) else {
+        return;
+    };
+    if let Ok(parsed) = S::parse(&prepared) {
+        let _ = S::part1(&parsed).to_string();
+        let _ = S::part2(&parsed).to_string();
+    }
+}